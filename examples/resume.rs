@@ -1,23 +1,13 @@
-use latex::{Document, Element, Section};
-use moderncv::{closesection, document_class, texify, CVPreamble, CVSection, Color, Style};
-
-fn subsection(section: &str) -> Element {
-    let s = texify!("subsection", section);
-    Element::UserDefined(s)
-}
-
-fn pagebreak() -> Element {
-    let empty = "";
-    let s = texify!("pagebreak", empty);
-    Element::UserDefined(s)
-}
+use latex::{Document, Section};
+use moderncv::description::Description;
+use moderncv::{document_class, CVDocument, CVPreamble, CVSection, Color, Style, TexStr};
 
 fn make_preamble(doc: &mut Document) {
     doc.preamble.cvtheme(Style::Casual,Some(Color::Green))
     .firstname("John")
     .familyname("Doe")
     .title("Design enthusiast")
-        .address("12 somestreet", Some("3456 somecity"), None)
+        .address("12 somestreet", Some("3456 somecity".into()), None)
     .mobile("+123 456 7890")
     .phone("+12 (3)456 78 90",None)
     .email("jdoe@design.org")
@@ -32,38 +22,37 @@ fn make_sections(doc: &mut Document) {
             "Degree",
             "Institution",
             "City",
-            Some(r"\textit{Grade}"),
-            Some("Description"),
+            Some(TexStr::raw(r"\textit{Grade}")),
+            Some("Description".into()),
         )
         .cventry(
             "year--year",
             "Degree",
             "Institution",
             "City",
-            Some(r"\textit{Grade}"),
-            Some("Description"),
+            Some(TexStr::raw(r"\textit{Grade}")),
+            Some("Description".into()),
         );
 
     doc.push(education);
 
     let mut thesis = Section::new("Master thesis");
     thesis
-        .cvline("title", r"\emph{Title}")
+        .cvline("title", TexStr::raw(r"\emph{Title}"))
         .cvline("supervisors", "Supervisors")
-        .cvline("description", r"\small Short thesis abstract");
+        .cvline("description", TexStr::raw(r"\small Short thesis abstract"));
 
     doc.push(thesis);
 
-    let mut experience = Section::new("Experience");
-    experience.push(subsection("Vocational"));
-    experience
+    let mut vocational = Section::new("Vocational");
+    vocational
         .cventry(
             "year--year",
             "Job title",
             "Employer",
             "City",
             None,
-            Some("Description"),
+            Some("Description".into()),
         )
         .cventry(
             "year--year",
@@ -71,20 +60,29 @@ fn make_sections(doc: &mut Document) {
             "Employer",
             "City",
             None,
-            Some("Description"),
+            Some("Description".into()),
         );
 
-    experience.push(subsection("Miscellaneous"));
-
-    experience.cventry(
+    let mut miscellaneous = Section::new("Miscellaneous");
+    miscellaneous.cventry(
         "year--year",
         "Job title",
         "Employer",
         "City",
         None,
-        Some(r"Description line 1\newline{}Description line 2"),
+        Some(
+            Description::new()
+                .paragraph("Description")
+                .bullets(vec!["Highlight 1", "Highlight 2"])
+                .to_tex(),
+        ),
     );
 
+    let mut experience = Section::new("Experience");
+    experience
+        .subsection(&vocational)
+        .subsection(&miscellaneous);
+
     doc.push(experience);
 
     let mut languages = Section::new("Languages");
@@ -103,13 +101,12 @@ fn make_sections(doc: &mut Document) {
 
     let mut interests = Section::new("Interests");
     interests
-        .cvline("hobby 1", r"\small Description")
-        .cvline("hobby 2", r"\small Description")
-        .cvline("hobby 3", r"\small Description");
+        .cvline("hobby 1", TexStr::raw(r"\small Description"))
+        .cvline("hobby 2", TexStr::raw(r"\small Description"))
+        .cvline("hobby 3", TexStr::raw(r"\small Description"));
 
     doc.push(interests);
-    doc.push(closesection());
-    doc.push(pagebreak());
+    doc.closesection().pagebreak();
 
     let mut extra = Section::new("Extra");
     extra
@@ -132,7 +129,7 @@ fn main() {
     let mut doc = Document::new(document_class());
 
     make_preamble(&mut doc);
-    doc.push(Element::TitlePage);
+    doc.makecvtitle();
     make_sections(&mut doc);
 
     println!("{}", latex::print(&doc).unwrap());
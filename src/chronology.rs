@@ -0,0 +1,222 @@
+//! Chronological order, gaps and overlaps of dated entries
+//!
+//! Entries dated with a [`DateRange`] can be sorted within their section, and
+//! checked for unexplained gaps or overlapping positions before the resume is
+//! sent out:
+//!
+//! ```
+//! use moderncv::chronology::Order;
+//! use moderncv::resume::{CVEntry, ResumeSection};
+//!
+//! let job = |years: &str, job: &str| CVEntry::Entry {
+//!     years: years.into(),
+//!     job: job.to_string().into(),
+//!     employer: "Company".into(),
+//!     localization: "".into(),
+//!     grade: None,
+//!     comment: None,
+//! };
+//!
+//! let mut experience = ResumeSection::new("Experience");
+//! experience
+//!     .push(job("2015--2017", "Developer"))
+//!     .push(job("2021--present", "Lead"))
+//!     .sort_chronologically(Order::NewestFirst);
+//!
+//! for issue in experience.lint(3) {
+//!     println!("{}", issue); // gap of 36 months between ...
+//! }
+//! ```
+//!
+//! Entries without dates stay where they are.
+
+use crate::date::{Date, DateRange, End};
+use std::cmp::Ordering;
+
+/// Order of dated entries in a section
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Order {
+    /// Latest end first, ongoing entries at the top
+    #[default]
+    NewestFirst,
+    /// Earliest start first
+    OldestFirst,
+}
+
+impl Order {
+    /// Compare two ranges in this order
+    pub fn compare(self, a: &DateRange, b: &DateRange) -> Ordering {
+        match self {
+            Self::NewestFirst => (last(b), b.start).cmp(&(last(a), a.start)),
+            Self::OldestFirst => a.cmp(b),
+        }
+    }
+}
+
+/// A dated entry, `label` names it in reports
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub label: String,
+    pub range: DateRange,
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.label.is_empty() {
+            write!(f, "{}", self.range)
+        } else {
+            write!(f, "`{}` ({})", self.label, self.range)
+        }
+    }
+}
+
+/// A finding of `lint`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Issue {
+    /// No entry covers the `months` between two entries
+    Gap {
+        previous: Span,
+        next: Span,
+        months: u32,
+    },
+    /// `second` starts before `first` ends
+    Overlap { first: Span, second: Span },
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Gap {
+                previous,
+                next,
+                months,
+            } => {
+                let unit = if *months == 1 { "month" } else { "months" };
+                write!(
+                    f,
+                    "gap of {} {} between {} and {}",
+                    months, unit, previous, next
+                )
+            }
+            Self::Overlap { first, second } => write!(f, "{} overlaps {}", second, first),
+        }
+    }
+}
+
+/// Report gaps longer than `max_gap_months` and overlaps between `spans`
+///
+/// Dates are compared at the coarser precision of the two, so `2019--2020`
+/// followed by `2020--2021` neither overlaps nor leaves a gap.
+pub fn lint(spans: &[Span], max_gap_months: u32) -> Vec<Issue> {
+    let mut spans = spans.iter().collect::<Vec<_>>();
+    spans.sort_by(|a, b| Order::OldestFirst.compare(&a.range, &b.range));
+
+    let mut issues = Vec::new();
+    let mut latest: Option<&Span> = None;
+    for span in spans {
+        if let Some(previous) = latest {
+            match end(&previous.range) {
+                Some(end) if !before(span.range.start, end) => {
+                    let months =
+                        month_index(span.range.start.first_day()) - month_index(end.last_day()) - 1;
+                    if months > max_gap_months as i32 {
+                        issues.push(Issue::Gap {
+                            previous: previous.clone(),
+                            next: span.clone(),
+                            months: months as u32,
+                        });
+                    }
+                }
+                _ => issues.push(Issue::Overlap {
+                    first: previous.clone(),
+                    second: span.clone(),
+                }),
+            }
+        }
+
+        let ends_later = match latest {
+            Some(latest) => last(&span.range) > last(&latest.range),
+            None => true,
+        };
+        if ends_later {
+            latest = Some(span);
+        }
+    }
+
+    issues
+}
+
+/// Sort the items dated by `range` in `order`, the others keep their position
+pub(crate) fn sort<T>(items: &mut Vec<T>, order: Order, range: impl Fn(&T) -> Option<DateRange>) {
+    let ranges = items.iter().map(range).collect::<Vec<_>>();
+    let slots = (0..items.len())
+        .filter(|&i| ranges[i].is_some())
+        .collect::<Vec<_>>();
+
+    let mut sorted = slots.clone();
+    sorted
+        .sort_by(|&a, &b| order.compare(ranges[a].as_ref().unwrap(), ranges[b].as_ref().unwrap()));
+
+    let mut taken = std::mem::take(items)
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+    let mut next = sorted.into_iter();
+    for i in 0..taken.len() {
+        let i = if slots.contains(&i) {
+            next.next().unwrap()
+        } else {
+            i
+        };
+        items.push(taken[i].take().unwrap());
+    }
+}
+
+/// The last date covered by `range`, `None` if ongoing
+fn end(range: &DateRange) -> Option<Date> {
+    match range.end {
+        None => Some(range.start),
+        Some(End::Date(end)) => Some(end),
+        Some(End::Present) => None,
+    }
+}
+
+fn last(range: &DateRange) -> End {
+    match end(range) {
+        Some(end) => End::Date(end.last_day()),
+        None => End::Present,
+    }
+}
+
+/// Whether `a` is before `b` at the coarser precision of the two
+fn before(a: Date, b: Date) -> bool {
+    let precision = precision(a).min(precision(b));
+
+    truncate(a, precision) < truncate(b, precision)
+}
+
+fn precision(date: Date) -> u8 {
+    date.month().is_some() as u8 + date.day().is_some() as u8
+}
+
+fn truncate(date: Date, precision: u8) -> (u16, u8, u8) {
+    match precision {
+        0 => (date.year(), 0, 0),
+        1 => (date.year(), date.month().unwrap_or(0), 0),
+        _ => (
+            date.year(),
+            date.month().unwrap_or(0),
+            date.day().unwrap_or(0),
+        ),
+    }
+}
+
+fn month_index(date: Date) -> i32 {
+    date.year() as i32 * 12 + date.month().unwrap_or(1) as i32 - 1
+}
@@ -0,0 +1,262 @@
+//! Options of the moderncv document class
+//!
+//! `latex::Document` always prints `\documentclass{moderncv}`, use
+//! [`ClassOptions::print`] to get `\documentclass[11pt,a4paper]{moderncv}` instead.
+
+use latex::{Document, DocumentClass, PreambleElement};
+use std::fmt::Write;
+
+/// Base font sizes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontSize {
+    Pt10,
+    Pt11,
+    Pt12,
+}
+
+impl std::fmt::Display for FontSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Pt10 => write!(f, "10pt"),
+            Self::Pt11 => write!(f, "11pt"),
+            Self::Pt12 => write!(f, "12pt"),
+        }
+    }
+}
+
+/// Paper sizes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaperSize {
+    A4,
+    A5,
+    B5,
+    Letter,
+    Legal,
+    Executive,
+}
+
+impl std::fmt::Display for PaperSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::A4 => write!(f, "a4paper"),
+            Self::A5 => write!(f, "a5paper"),
+            Self::B5 => write!(f, "b5paper"),
+            Self::Letter => write!(f, "letterpaper"),
+            Self::Legal => write!(f, "legalpaper"),
+            Self::Executive => write!(f, "executivepaper"),
+        }
+    }
+}
+
+/// Font families
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontFamily {
+    Sans,
+    Roman,
+}
+
+impl std::fmt::Display for FontFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Sans => write!(f, "sans"),
+            Self::Roman => write!(f, "roman"),
+        }
+    }
+}
+
+/// A moderncv class option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassOption {
+    FontSize(FontSize),
+    PaperSize(PaperSize),
+    FontFamily(FontFamily),
+    Landscape,
+    /// Show overfull lines and skip images
+    Draft,
+    /// Undo `Draft`, e.g. when it is set globally
+    Final,
+    /// Color links instead of boxing them, passed on to hyperref
+    ColorLinks,
+}
+
+impl ClassOption {
+    /// Options of the same kind exclude each other
+    fn conflicts_with(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::FontSize(_), Self::FontSize(_))
+            | (Self::PaperSize(_), Self::PaperSize(_))
+            | (Self::FontFamily(_), Self::FontFamily(_))
+            | (Self::Draft, Self::Final)
+            | (Self::Final, Self::Draft) => true,
+            _ => self == other,
+        }
+    }
+}
+
+impl std::fmt::Display for ClassOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FontSize(size) => write!(f, "{}", size),
+            Self::PaperSize(size) => write!(f, "{}", size),
+            Self::FontFamily(family) => write!(f, "{}", family),
+            Self::Landscape => write!(f, "landscape"),
+            Self::Draft => write!(f, "draft"),
+            Self::Final => write!(f, "final"),
+            Self::ColorLinks => write!(f, "colorlinks"),
+        }
+    }
+}
+
+/// Errors of invalid class options
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Two options which exclude each other, e.g. `11pt` and `12pt`
+    Conflict(ClassOption, ClassOption),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Conflict(a, b) => write!(f, "class option `{}` conflicts with `{}`", a, b),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Builder of the moderncv class options
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClassOptions {
+    options: Vec<ClassOption>,
+}
+
+impl ClassOptions {
+    /// Create empty class options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an option
+    pub fn push(&mut self, option: ClassOption) -> &mut Self {
+        self.options.push(option);
+
+        self
+    }
+
+    /// Set the base font size
+    pub fn font_size(&mut self, size: FontSize) -> &mut Self {
+        self.push(ClassOption::FontSize(size))
+    }
+
+    /// Set the paper size
+    pub fn paper_size(&mut self, size: PaperSize) -> &mut Self {
+        self.push(ClassOption::PaperSize(size))
+    }
+
+    /// Set the font family
+    pub fn font_family(&mut self, family: FontFamily) -> &mut Self {
+        self.push(ClassOption::FontFamily(family))
+    }
+
+    /// Use landscape orientation
+    pub fn landscape(&mut self) -> &mut Self {
+        self.push(ClassOption::Landscape)
+    }
+
+    /// Enable draft mode
+    pub fn draft(&mut self) -> &mut Self {
+        self.push(ClassOption::Draft)
+    }
+
+    /// Disable draft mode
+    pub fn final_(&mut self) -> &mut Self {
+        self.push(ClassOption::Final)
+    }
+
+    /// Color links instead of boxing them
+    pub fn colorlinks(&mut self) -> &mut Self {
+        self.push(ClassOption::ColorLinks)
+    }
+
+    /// The options in order
+    pub fn options(&self) -> &[ClassOption] {
+        &self.options
+    }
+
+    /// Check that no two options exclude each other
+    pub fn validate(&self) -> Result<(), Error> {
+        for (i, a) in self.options.iter().enumerate() {
+            if let Some(b) = self.options[i + 1..].iter().find(|b| a.conflicts_with(**b)) {
+                return Err(Error::Conflict(*a, *b));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The `\documentclass[...]{moderncv}` line
+    pub fn to_documentclass(&self) -> Result<String, Error> {
+        self.validate()?;
+
+        if self.options.is_empty() {
+            return Ok(r"\documentclass{moderncv}".to_string());
+        }
+
+        let options = self
+            .options
+            .iter()
+            .map(|option| option.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok(format!(r"\documentclass[{}]{{moderncv}}", options))
+    }
+
+    /// Print a document with these class options, like `latex::print`
+    ///
+    /// Partial documents are printed without `\documentclass` and preamble.
+    pub fn print(&self, doc: &Document) -> Result<String, Error> {
+        let documentclass = self.to_documentclass()?;
+
+        let mut body = Document::new(DocumentClass::Part);
+        body.push_doc(doc);
+        let body = latex::print(&body).expect("printing to a buffer never fails");
+        if doc.class == DocumentClass::Part {
+            return Ok(body);
+        }
+
+        let mut rendered = String::new();
+        writeln!(rendered, "{}", documentclass).unwrap();
+        for elem in doc.preamble.iter() {
+            match elem {
+                PreambleElement::UsePackage {
+                    package,
+                    argument: None,
+                } => writeln!(rendered, r"\usepackage{{{}}}", package),
+                PreambleElement::UsePackage {
+                    package,
+                    argument: Some(argument),
+                } => writeln!(rendered, r"\usepackage[{}]{{{}}}", argument, package),
+                PreambleElement::UserDefined(s) => writeln!(rendered, "{}", s),
+            }
+            .unwrap();
+        }
+
+        let (title, author) = (&doc.preamble.title, &doc.preamble.author);
+        if !doc.preamble.is_empty() && (title.is_some() || author.is_some()) {
+            rendered.push('\n');
+        }
+        if let Some(title) = title {
+            writeln!(rendered, r"\title{{{}}}", title).unwrap();
+        }
+        if let Some(author) = author {
+            writeln!(rendered, r"\author{{{}}}", author).unwrap();
+        }
+
+        writeln!(rendered, r"\begin{{document}}").unwrap();
+        rendered.push_str(&body);
+        writeln!(rendered, r"\end{{document}}").unwrap();
+
+        Ok(rendered)
+    }
+}
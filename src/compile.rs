@@ -0,0 +1,421 @@
+//! Compile LaTeX sources to PDF with a locally installed engine
+//!
+//! The source is compiled in a temporary directory together with its assets,
+//! the `.log` file is parsed into [`Diagnostic`]s. When compiling a
+//! [`Resume`], diagnostics are mapped back to the entry which produced the
+//! offending line.
+
+use crate::render::RenderOptions;
+use crate::resume::Resume;
+use crate::unicode::Encoder;
+use latex::Element;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Name of the source file in the temporary directory
+const JOBNAME: &str = "resume";
+
+/// Extensions tried by `graphicx` for a file name without one
+const GRAPHICS_EXTENSIONS: [&str; 4] = ["pdf", "png", "jpg", "jpeg"];
+
+pub use crate::Engine;
+
+fn command(engine: Engine, tex: &str) -> Command {
+    let mut command = Command::new(engine.program());
+    if engine == Engine::Latexmk {
+        command.arg("-pdf");
+    }
+    command
+        .arg("-interaction=nonstopmode")
+        .arg("-halt-on-error")
+        .arg(tex);
+
+    command
+}
+
+/// Severity of a diagnostic
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// What a diagnostic is about
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// An unknown command, e.g. a typo or a missing package
+    UndefinedControlSequence(String),
+    /// A file such as a photo or a package which could not be found
+    MissingFile(String),
+    /// `moderncv.cls` is not installed
+    MissingClass,
+    /// A line or page which is too wide or too high
+    OverfullBox,
+    /// Any other error
+    Other,
+}
+
+/// Position of an entry in a resume
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryRef {
+    /// Index of the section
+    pub section: usize,
+    /// Index of the entry in the section
+    pub entry: usize,
+    /// Title of the section
+    pub title: String,
+}
+
+/// A problem reported in the log
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub kind: DiagnosticKind,
+    /// The message as written in the log
+    pub message: String,
+    /// Line of the source file
+    pub line: Option<usize>,
+    /// Resume entry which produced the line
+    pub entry: Option<EntryRef>,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "error")?,
+            Severity::Warning => write!(f, "warning")?,
+        }
+
+        if let Some(line) = self.line {
+            write!(f, " at line {}", line)?;
+        }
+
+        if let Some(entry) = &self.entry {
+            write!(f, " (section {:?}, entry {})", entry.title, entry.entry + 1)?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Result of a successful compilation
+#[derive(Clone, Debug)]
+pub struct Output {
+    /// The generated PDF
+    pub pdf: Vec<u8>,
+    /// Content of the `.log` file
+    pub log: String,
+    /// Warnings found in the log
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Errors of compiling a document
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to prepare the temporary directory or to run the engine
+    Io(std::io::Error),
+    /// The engine is not installed
+    EngineNotFound(Engine),
+    /// A relative asset path which leaves the source directory, e.g. `../photo.jpg`
+    InvalidAsset(PathBuf),
+    /// An asset such as the photo which does not exist
+    MissingAsset(PathBuf),
+    /// The engine failed, see the diagnostics
+    Failed {
+        log: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::EngineNotFound(engine) => write!(f, "{} is not installed", engine),
+            Self::InvalidAsset(path) => {
+                write!(f, "{} is outside of the source directory", path.display())
+            }
+            Self::MissingAsset(path) => write!(f, "{} not found", path.display()),
+            Self::Failed { diagnostics, .. } => {
+                match diagnostics.iter().find(|d| d.severity == Severity::Error) {
+                    Some(diagnostic) => write!(f, "compilation failed, {}", diagnostic),
+                    None => write!(f, "compilation failed"),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Maps lines of a rendered resume back to its entries
+#[derive(Clone, Debug, Default)]
+pub struct SourceMap {
+    entries: Vec<(usize, usize, EntryRef)>,
+}
+
+impl SourceMap {
+    /// Locate the entries of `resume` in `source`, rendered with `options`
+    pub fn new(source: &str, resume: &Resume, options: &RenderOptions) -> Self {
+        let mut encoder = Encoder::new(options.engine, options.unicode);
+        let mut entries = Vec::new();
+        let mut offset = 0;
+
+        for (i, section) in resume.sections.iter().enumerate() {
+            for (j, entry) in section.rendered_entries() {
+                let rendered = match entry.to_element_with(options) {
+                    Element::UserDefined(s) => encoder.encode(&s),
+                    _ => continue,
+                };
+
+                let start = match source[offset..].find(&rendered) {
+                    Some(start) => offset + start,
+                    None => continue,
+                };
+                offset = start + rendered.len();
+
+                let first = source[..start].matches('\n').count() + 1;
+                let last = first + rendered.matches('\n').count();
+                let entry = EntryRef {
+                    section: i,
+                    entry: j,
+                    title: section.title.clone(),
+                };
+                entries.push((first, last, entry));
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Entry which produced the one-based `line`
+    pub fn entry(&self, line: usize) -> Option<&EntryRef> {
+        self.entries
+            .iter()
+            .find(|(first, last, _)| (*first..=*last).contains(&line))
+            .map(|(_, _, entry)| entry)
+    }
+}
+
+/// Runs a LaTeX engine in a temporary directory
+#[derive(Clone, Debug)]
+pub struct Compiler {
+    engine: Engine,
+    assets: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Compiler {
+    /// Create a compiler for `engine`
+    pub fn new(engine: Engine) -> Self {
+        Self {
+            engine,
+            assets: Vec::new(),
+        }
+    }
+
+    /// Copy a file next to the source, keeping its file name
+    pub fn asset<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        let path = path.as_ref();
+        if let Some(name) = path.file_name() {
+            self.assets.push((path.to_path_buf(), PathBuf::from(name)));
+        }
+
+        self
+    }
+
+    /// Compile a LaTeX source
+    pub fn compile(&self, source: &str) -> Result<Output, Error> {
+        self.run(source, &self.assets, None)
+    }
+
+    /// Compile `source`, the rendering of `resume` with `options`
+    ///
+    /// The photo is looked up relative to `base_dir` and must stay below it.
+    /// Without an extension, the `.pdf`, `.png`, `.jpg` and `.jpeg` files are
+    /// tried like `graphicx` does.
+    pub fn compile_resume<P: AsRef<Path>>(
+        &self,
+        resume: &Resume,
+        options: &RenderOptions,
+        source: &str,
+        base_dir: P,
+    ) -> Result<Output, Error> {
+        let mut assets = self.assets.clone();
+        if let Some(photo) = &resume.personal.photo {
+            let file = Path::new(&photo.file);
+            if file.is_relative() {
+                if !file.components().all(|c| matches!(c, Component::Normal(_))) {
+                    return Err(Error::InvalidAsset(file.to_path_buf()));
+                }
+                assets.push(photo_asset(base_dir.as_ref(), file)?);
+            }
+        }
+
+        let map = SourceMap::new(source, resume, options);
+
+        self.run(source, &assets, Some(&map))
+    }
+
+    fn run(
+        &self,
+        source: &str,
+        assets: &[(PathBuf, PathBuf)],
+        map: Option<&SourceMap>,
+    ) -> Result<Output, Error> {
+        let dir = tempfile::tempdir()?;
+        let tex = format!("{}.tex", JOBNAME);
+        std::fs::write(dir.path().join(&tex), source)?;
+
+        for (from, to) in assets {
+            if !from.is_file() {
+                return Err(Error::MissingAsset(from.clone()));
+            }
+            let to = dir.path().join(to);
+            if let Some(parent) = to.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(from, to)?;
+        }
+
+        let status = command(self.engine, &tex)
+            .current_dir(dir.path())
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => Error::EngineNotFound(self.engine),
+                _ => Error::Io(e),
+            })?;
+
+        let log = std::fs::read(dir.path().join(format!("{}.log", JOBNAME)))
+            .map(|log| String::from_utf8_lossy(&log).into_owned())
+            .unwrap_or_default();
+
+        let mut diagnostics = parse_log(&log);
+        if let Some(map) = map {
+            for diagnostic in &mut diagnostics {
+                diagnostic.entry = diagnostic.line.and_then(|line| map.entry(line)).cloned();
+            }
+        }
+
+        let pdf = match std::fs::read(dir.path().join(format!("{}.pdf", JOBNAME))) {
+            Ok(pdf) if status.success() => pdf,
+            _ => return Err(Error::Failed { log, diagnostics }),
+        };
+
+        Ok(Output {
+            pdf,
+            log,
+            diagnostics,
+        })
+    }
+}
+
+/// Source and destination of the photo `file`, relative to `base_dir`
+fn photo_asset(base_dir: &Path, file: &Path) -> Result<(PathBuf, PathBuf), Error> {
+    let from = base_dir.join(file);
+    if from.is_file() || file.extension().is_some() {
+        return Ok((from, file.to_path_buf()));
+    }
+
+    GRAPHICS_EXTENSIONS
+        .iter()
+        .map(|extension| file.with_extension(extension))
+        .find(|file| base_dir.join(file).is_file())
+        .map(|file| (base_dir.join(&file), file))
+        .ok_or(Error::MissingAsset(from))
+}
+
+/// Parse a TeX `.log` file into diagnostics
+pub fn parse_log(log: &str) -> Vec<Diagnostic> {
+    let lines: Vec<&str> = log.lines().collect();
+    let mut diagnostics = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(message) = line.strip_prefix("! ") {
+            // The context `l.<line> <text>` follows the error within a few lines
+            let context = lines[i + 1..]
+                .iter()
+                .take(10)
+                .take_while(|line| !line.starts_with("! "))
+                .find_map(|line| context_line(line));
+
+            let kind = if message.starts_with("Undefined control sequence") {
+                let command = context
+                    .and_then(|(_, text)| text.rfind('\\').map(|i| &text[i..]))
+                    .unwrap_or_default();
+                DiagnosticKind::UndefinedControlSequence(command.trim_end().to_string())
+            } else if let Some(file) = missing_file(message) {
+                if file == "moderncv.cls" {
+                    DiagnosticKind::MissingClass
+                } else {
+                    DiagnosticKind::MissingFile(file.to_string())
+                }
+            } else {
+                DiagnosticKind::Other
+            };
+
+            diagnostics.push(Diagnostic {
+                severity: Severity::Error,
+                kind,
+                message: message.to_string(),
+                line: context.map(|(line, _)| line),
+                entry: None,
+            });
+        } else if line.starts_with("Overfull \\hbox") || line.starts_with("Overfull \\vbox") {
+            let line_number = line
+                .rfind(" at line")
+                .and_then(|i| line[i..].split_whitespace().nth(2))
+                .and_then(|lines| lines.split("--").next())
+                .and_then(|line| line.parse().ok());
+
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                kind: DiagnosticKind::OverfullBox,
+                message: line.to_string(),
+                line: line_number,
+                entry: None,
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Split `l.42 \foo` into the line number and the text
+fn context_line(line: &str) -> Option<(usize, &str)> {
+    let rest = line.strip_prefix("l.")?;
+    let end = rest.find(|c: char| !c.is_ascii_digit())?;
+    let number = rest[..end].parse().ok()?;
+
+    Some((number, &rest[end..]))
+}
+
+/// File name of `File `x' not found` or `Unable to load picture or PDF file 'x'`
+fn missing_file(message: &str) -> Option<&str> {
+    let (start, end) = if message.contains("' not found") {
+        (message.find("File `")? + "File `".len(), "'")
+    } else if message.starts_with("Unable to load picture or PDF file '") {
+        ("Unable to load picture or PDF file '".len(), "'")
+    } else {
+        return None;
+    };
+
+    let rest = &message[start..];
+
+    Some(&rest[..rest.find(end)?])
+}
@@ -0,0 +1,688 @@
+//! Dates and date ranges of entries
+//!
+//! A [`DateRange`] converts into the first argument of `\cventry` as ISO
+//! dates, or in a [`DateFormat`] and [`Language`] with `to_tex`. A `Resume`
+//! takes them from its `RenderOptions`:
+//!
+//! ```
+//! use moderncv::date::{Date, DateFormat, DateRange, Language};
+//! use moderncv::section::cventry;
+//!
+//! // "März 2019--heute"
+//! let range = DateRange::new(Date::with_month(2019, 3).unwrap()).present();
+//! let years = range.to_tex(DateFormat::Long, Language::German);
+//! let entry = cventry(years, "Engineer", "Company", "City", None, None);
+//! ```
+//!
+//! Ranges parse from strings like `2019--2021`, `Jan 2019 -- present` or
+//! `2019-01 – 2021-06`, and are ordered chronologically.
+
+#[cfg(feature = "serde")]
+use crate::tex::visit_text;
+use crate::tex::{escape, TexStr};
+
+/// How dates are rendered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum DateFormat {
+    /// `2019-03`, `2019-03-15`
+    #[default]
+    Iso,
+    /// `03/2019`, `15/03/2019`, with dots in German
+    Numeric,
+    /// `Mar 2019`, `15 Mar 2019`
+    Short,
+    /// `March 2019`, `15 March 2019`
+    Long,
+}
+
+/// Language of month names and of the open end of ranges
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    French,
+}
+
+const LANGUAGES: [Language; 3] = [Language::English, Language::German, Language::French];
+
+impl Language {
+    /// The open end of a range, e.g. `present`
+    pub fn present(self) -> &'static str {
+        match self {
+            Self::English => "present",
+            Self::German => "heute",
+            Self::French => "aujourd'hui",
+        }
+    }
+
+    fn months(self, long: bool) -> [&'static str; 12] {
+        match (self, long) {
+            (Self::English, true) => [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            (Self::English, false) => [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            (Self::German, true) => [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            (Self::German, false) => [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sep.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            (Self::French, true) => [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            (Self::French, false) => [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+        }
+    }
+}
+
+/// Errors of invalid dates
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DateError {
+    /// Not a year, year and month, or full date
+    Invalid(String),
+    /// The range ends before it starts
+    Reversed(String),
+}
+
+impl std::fmt::Display for DateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(s) => write!(
+                f,
+                "invalid date `{}`, expected `YYYY`, `YYYY-MM`, `YYYY-MM-DD` or `Month YYYY`",
+                s
+            ),
+            Self::Reversed(s) => write!(f, "date range `{}` ends before it starts", s),
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// A year, a month or a day
+///
+/// Dates are ordered by year, then month, then day, a year comes before its months.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl Date {
+    /// Make a year
+    pub fn new(year: u16) -> Self {
+        Self {
+            year,
+            month: None,
+            day: None,
+        }
+    }
+
+    /// Make a month of `year`, `month` is between 1 and 12
+    pub fn with_month(year: u16, month: u8) -> Result<Self, DateError> {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::Invalid(format!("{}-{:02}", year, month)));
+        }
+
+        Ok(Self {
+            year,
+            month: Some(month),
+            day: None,
+        })
+    }
+
+    /// Make a day of `year`
+    pub fn with_day(year: u16, month: u8, day: u8) -> Result<Self, DateError> {
+        let invalid = || DateError::Invalid(format!("{}-{:02}-{:02}", year, month, day));
+        let date = Self::with_month(year, month).map_err(|_| invalid())?;
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            day: Some(day),
+            ..date
+        })
+    }
+
+    /// The year
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month, if any
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// The day of the month, if any
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// The first day covered by the date, e.g. January 1st of a year
+    pub fn first_day(&self) -> Self {
+        let month = self.month.unwrap_or(1);
+
+        Self {
+            year: self.year,
+            month: Some(month),
+            day: Some(self.day.unwrap_or(1)),
+        }
+    }
+
+    /// The last day covered by the date, e.g. December 31st of a year
+    pub fn last_day(&self) -> Self {
+        let month = self.month.unwrap_or(12);
+
+        Self {
+            year: self.year,
+            month: Some(month),
+            day: Some(self.day.unwrap_or_else(|| days_in_month(self.year, month))),
+        }
+    }
+
+    /// Render the date in `format` and `language`
+    pub fn format(&self, format: DateFormat, language: Language) -> String {
+        let (month, day) = match (self.month, self.day) {
+            (Some(month), day) => (month, day),
+            (None, _) => return self.year.to_string(),
+        };
+
+        match format {
+            DateFormat::Iso => self.to_string(),
+            DateFormat::Numeric => {
+                let sep = if language == Language::German {
+                    '.'
+                } else {
+                    '/'
+                };
+                match day {
+                    Some(day) => format!("{:02}{}{:02}{}{}", day, sep, month, sep, self.year),
+                    None => format!("{:02}{}{}", month, sep, self.year),
+                }
+            }
+            DateFormat::Short | DateFormat::Long => {
+                let name = language.months(format == DateFormat::Long)[month as usize - 1];
+                match (day, language) {
+                    (Some(day), Language::German) => format!("{}. {} {}", day, name, self.year),
+                    (Some(day), _) => format!("{} {} {}", day, name, self.year),
+                    (None, _) => format!("{} {}", name, self.year),
+                }
+            }
+        }
+    }
+}
+
+/// Formatted as `YYYY`, `YYYY-MM` or `YYYY-MM-DD`
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse `YYYY`, `YYYY-MM`, `YYYY-MM-DD`, `Month YYYY` or `DD Month YYYY`
+///
+/// Month names of any supported language are accepted, abbreviated or not,
+/// as well as the numeric `MM/YYYY` and `DD.MM.YYYY` forms.
+impl std::str::FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DateError::Invalid(s.to_string());
+        let number = |part: &str, len: std::ops::RangeInclusive<usize>| {
+            if len.contains(&part.len()) && part.bytes().all(|b| b.is_ascii_digit()) {
+                part.parse::<u16>().map_err(|_| invalid())
+            } else {
+                Err(invalid())
+            }
+        };
+        let year = |part: &str| number(part, 4..=4);
+        let day = |part: &str| number(part.trim_end_matches('.'), 1..=2).map(|day| day as u8);
+        let month = |part: &str| match number(part, 1..=2) {
+            Ok(month) => Ok(month as u8),
+            Err(_) => month_number(part).ok_or_else(invalid),
+        };
+
+        let words = s.split_whitespace().collect::<Vec<_>>();
+        match words[..] {
+            [date] if date.contains(['/', '.']) => {
+                match date.split(['/', '.']).collect::<Vec<_>>()[..] {
+                    [m, y] => Self::with_month(year(y)?, month(m)?).map_err(|_| invalid()),
+                    [d, m, y] => {
+                        Self::with_day(year(y)?, month(m)?, day(d)?).map_err(|_| invalid())
+                    }
+                    _ => Err(invalid()),
+                }
+            }
+            [date] => match date.split('-').collect::<Vec<_>>()[..] {
+                [y] => Ok(Self::new(year(y)?)),
+                [y, m] => Self::with_month(year(y)?, month(m)?).map_err(|_| invalid()),
+                [y, m, d] => Self::with_day(year(y)?, month(m)?, day(d)?).map_err(|_| invalid()),
+                _ => Err(invalid()),
+            },
+            [m, y] => Self::with_month(year(y)?, month(m)?).map_err(|_| invalid()),
+            [d, m, y] => Self::with_day(year(y)?, month(m)?, day(d)?).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// The end of a date range
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum End {
+    /// Ended at this date
+    Date(Date),
+    /// Still ongoing, rendered as `present` in the current language
+    Present,
+}
+
+/// A date, or a range of dates which may still be ongoing
+///
+/// Ranges are ordered by start, then by end; a single date comes first and
+/// an ongoing range last.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateRange {
+    pub start: Date,
+    /// `None` for a single date
+    pub end: Option<End>,
+}
+
+impl DateRange {
+    /// Make a single date
+    pub fn new(start: Date) -> Self {
+        Self { start, end: None }
+    }
+
+    /// End the range at `end`
+    pub fn until(self, end: Date) -> Self {
+        Self {
+            end: Some(End::Date(end)),
+            ..self
+        }
+    }
+
+    /// Leave the range open
+    pub fn present(self) -> Self {
+        Self {
+            end: Some(End::Present),
+            ..self
+        }
+    }
+
+    /// Whether the range is still ongoing
+    pub fn is_ongoing(&self) -> bool {
+        self.end == Some(End::Present)
+    }
+
+    /// Render the range in `format` and `language`, separated by `--`
+    pub fn format(&self, format: DateFormat, language: Language) -> String {
+        let start = self.start.format(format, language);
+        match self.end {
+            None => start,
+            Some(End::Date(end)) => format!("{}--{}", start, end.format(format, language)),
+            Some(End::Present) => format!("{}--{}", start, language.present()),
+        }
+    }
+
+    /// Render the range in `format` and `language` as a command argument
+    pub fn to_tex(&self, format: DateFormat, language: Language) -> TexStr<'static> {
+        TexStr::raw(escape(&self.format(format, language)).into_owned())
+    }
+}
+
+impl From<Date> for DateRange {
+    fn from(date: Date) -> Self {
+        Self::new(date)
+    }
+}
+
+/// Formatted as ISO dates separated by `--`, the input of `from_str`
+impl std::fmt::Display for DateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(DateFormat::Iso, Language::English))
+    }
+}
+
+/// Parse a date, or two dates separated by `--`, `–` or `—`
+///
+/// The end may be `present`, `now`, `today` or the word of any supported language.
+impl std::str::FromStr for DateRange {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = match ["--", "–", "—"].iter().find_map(|sep| s.split_once(sep)) {
+            Some((start, end)) => (start, Some(end.trim())),
+            None => (s, None),
+        };
+
+        let range = Self::new(start.parse()?);
+        let end = match end {
+            None => return Ok(range),
+            Some(end) if is_present(end) => return Ok(range.present()),
+            Some(end) => end.parse::<Date>()?,
+        };
+
+        if end.last_day() < range.start.first_day() {
+            return Err(DateError::Reversed(s.trim().to_string()));
+        }
+
+        Ok(range.until(end))
+    }
+}
+
+/// Rendered as ISO dates
+impl From<DateRange> for TexStr<'static> {
+    fn from(range: DateRange) -> Self {
+        (&range).into()
+    }
+}
+
+/// Rendered as ISO dates
+impl From<&DateRange> for TexStr<'static> {
+    fn from(range: &DateRange) -> Self {
+        range.to_tex(DateFormat::Iso, Language::English)
+    }
+}
+
+/// The first argument of `\cventry`: a date range, or free text
+///
+/// Text is rendered as written, and parsed as a [`DateRange`] only to sort
+/// and lint entries. In resume files, a map with `start` and an optional `end`
+/// makes a range rendered in the chosen format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "YearsRepr"))]
+pub enum Years {
+    Range(DateRange),
+    Text(TexStr<'static>),
+}
+
+impl Years {
+    /// The date range, parsed from plain text if needed
+    pub fn range(&self) -> Option<DateRange> {
+        match self {
+            Self::Range(range) => Some(*range),
+            Self::Text(TexStr::Text(s)) => s.parse().ok(),
+            Self::Text(TexStr::Raw(_)) => None,
+        }
+    }
+
+    /// Render the years as a command argument, a range in `format` and `language`
+    pub fn to_tex(&self, format: DateFormat, language: Language) -> TexStr<'static> {
+        match self {
+            Self::Range(range) => range.to_tex(format, language),
+            Self::Text(text) => text.clone(),
+        }
+    }
+}
+
+impl Default for Years {
+    fn default() -> Self {
+        Self::Text(TexStr::default())
+    }
+}
+
+impl From<DateRange> for Years {
+    fn from(range: DateRange) -> Self {
+        Self::Range(range)
+    }
+}
+
+impl From<TexStr<'static>> for Years {
+    fn from(text: TexStr<'static>) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Years {
+    fn from(text: &str) -> Self {
+        Self::Text(TexStr::text(text.to_string()))
+    }
+}
+
+impl From<String> for Years {
+    fn from(text: String) -> Self {
+        Self::Text(TexStr::text(text))
+    }
+}
+
+/// A range is rendered as ISO dates
+impl<'a> From<&'a Years> for TexStr<'a> {
+    fn from(years: &'a Years) -> Self {
+        years.to_tex(DateFormat::Iso, Language::English)
+    }
+}
+
+/// Text, or a range as `{ start: 2019-03, end: present }`
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum YearsRepr {
+    Range {
+        /// A date like `2019`, `2019-03`, `Mar 2019` or `15.03.2019`
+        #[cfg_attr(feature = "schema", schemars(schema_with = "date_schema"))]
+        start: String,
+        /// A date or `present`, a single date if left out
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "schema", schemars(schema_with = "date_schema"))]
+        end: Option<String>,
+    },
+    Text(TexStr<'static>),
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Years {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct YearsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for YearsVisitor {
+            type Value = Years;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "years as text, or a date range as `{{ start, end }}`")
+            }
+
+            visit_text!(Years::Text);
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                const FIELDS: &[&str] = &["start", "end", "raw"];
+                let (mut start, mut end, mut raw) = (None, None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "start" => start = Some(map.next_value::<DateArg>()?.0),
+                        "end" => end = Some(map.next_value::<DateArg>()?.0),
+                        "raw" => raw = Some(map.next_value::<String>()?),
+                        _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+                    }
+                }
+
+                match (start, end, raw) {
+                    (None, None, Some(raw)) => Ok(Years::Text(TexStr::raw(raw))),
+                    (_, _, Some(_)) => Err(A::Error::custom(
+                        "`raw` cannot be combined with `start` and `end`",
+                    )),
+                    (None, _, None) => Err(A::Error::missing_field("start")),
+                    (Some(start), end, None) => {
+                        let field = |field: &'static str| {
+                            move |e: DateError| A::Error::custom(format!("{}: {}", field, e))
+                        };
+                        start.parse::<Date>().map_err(field("start"))?;
+                        let range = match end {
+                            None => start,
+                            Some(end) => {
+                                if !is_present(&end) {
+                                    end.parse::<Date>().map_err(field("end"))?;
+                                }
+                                format!("{}--{}", start, end)
+                            }
+                        };
+
+                        range.parse().map(Years::Range).map_err(A::Error::custom)
+                    }
+                }
+            }
+        }
+
+        deserializer.deserialize_any(YearsVisitor)
+    }
+}
+
+/// The `start` or `end` of a range, a string or a year
+#[cfg(feature = "serde")]
+struct DateArg(String);
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DateArg {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DateArgVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DateArgVisitor {
+            type Value = DateArg;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "a date like `2019-03`, or a year")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                Ok(DateArg(s.to_string()))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(DateArg(v.to_string()))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(DateArg(v.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(DateArgVisitor)
+    }
+}
+
+#[cfg(feature = "schema")]
+fn date_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    use schemars::schema::{InstanceType, SchemaObject};
+
+    SchemaObject {
+        instance_type: Some(vec![InstanceType::String, InstanceType::Integer].into()),
+        ..SchemaObject::default()
+    }
+    .into()
+}
+
+#[cfg(feature = "serde")]
+impl From<Years> for YearsRepr {
+    fn from(years: Years) -> Self {
+        match years {
+            Years::Range(range) => YearsRepr::Range {
+                start: range.start.to_string(),
+                end: range.end.map(|end| match end {
+                    End::Date(end) => end.to_string(),
+                    End::Present => Language::English.present().to_string(),
+                }),
+            },
+            Years::Text(text) => YearsRepr::Text(text),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Years {
+    fn schema_name() -> String {
+        "Years".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        YearsRepr::json_schema(gen)
+    }
+}
+
+fn is_present(s: &str) -> bool {
+    let s = s.to_lowercase();
+
+    ["now", "today", "current", "ongoing"].contains(&s.as_str())
+        || LANGUAGES.iter().any(|language| s == language.present())
+}
+
+/// Month number of a name in any supported language, abbreviated or not
+fn month_number(name: &str) -> Option<u8> {
+    let name = name.trim_end_matches('.').to_lowercase();
+    let matches = |month: &str| month.trim_end_matches('.').to_lowercase() == name;
+
+    LANGUAGES.iter().find_map(|language| {
+        language
+            .months(true)
+            .iter()
+            .zip(language.months(false).iter())
+            .position(|(long, short)| matches(long) || matches(short))
+            .map(|i| i as u8 + 1)
+    })
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    let year = i32::from(year);
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
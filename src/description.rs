@@ -0,0 +1,480 @@
+//! Entry descriptions made of paragraphs and bullet lists
+//!
+//! A [`Description`] renders into a single command argument, e.g. the sixth
+//! argument of `\cventry` or the text of `\cvitem`. Paragraphs are separated
+//! by `\newline{}` since moderncv commands do not accept `\par`, bullet lists
+//! become `itemize` environments with compact spacing.
+//!
+//! ```
+//! use latex::Section;
+//! use moderncv::description::{Bullet, Description};
+//! use moderncv::CVSection;
+//!
+//! let mut lead = Bullet::new("Led the backend team");
+//! lead.push("Hired five engineers");
+//!
+//! let description = Description::new()
+//!     .paragraph("Payment platform for small businesses")
+//!     .bullets(vec![lead, "Cut latency by 40%".into()])
+//!     .to_tex();
+//!
+//! let mut experience = Section::new("Experience");
+//! experience.cventry("2020--2023", "Engineer", "Company", "City", None, Some(description));
+//! ```
+
+use crate::render::RenderOptions;
+use crate::tex::TexStr;
+#[cfg(feature = "serde")]
+use crate::tex::{visit_text, TexStrVisitor};
+
+/// Deepest level of nested bullets, deeper bullets are rendered at this level
+pub const MAX_DEPTH: usize = 3;
+
+const BEGIN_ITEMIZE: &str =
+    r"\begin{itemize}\setlength{\itemsep}{0pt}\setlength{\parskip}{0pt}\setlength{\parsep}{0pt}";
+const END_ITEMIZE: &str = r"\end{itemize}";
+
+/// A bullet with its nested bullets
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(into = "BulletRepr")
+)]
+pub struct Bullet {
+    pub text: TexStr<'static>,
+    pub bullets: Vec<Bullet>,
+}
+
+impl Bullet {
+    /// Create a bullet without nested bullets
+    pub fn new(text: impl Into<TexStr<'static>>) -> Self {
+        Self {
+            text: text.into(),
+            bullets: Vec::new(),
+        }
+    }
+
+    /// Add a nested bullet
+    pub fn push(&mut self, bullet: impl Into<Bullet>) -> &mut Self {
+        self.bullets.push(bullet.into());
+
+        self
+    }
+
+    fn render(&self, depth: usize, options: &RenderOptions, lines: &mut Vec<String>) {
+        lines.push(format!(r"\item {}", options.free_text(&self.text)));
+        if self.bullets.is_empty() {
+            return;
+        }
+
+        if depth < MAX_DEPTH {
+            render_bullets(&self.bullets, depth + 1, options, lines);
+        } else {
+            for bullet in &self.bullets {
+                bullet.render(depth, options, lines);
+            }
+        }
+    }
+}
+
+impl From<&str> for Bullet {
+    fn from(text: &str) -> Self {
+        Self::new(text.to_string())
+    }
+}
+
+impl From<String> for Bullet {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<TexStr<'static>> for Bullet {
+    fn from(text: TexStr<'static>) -> Self {
+        Self::new(text)
+    }
+}
+
+/// A part of a description
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "BlockRepr"))]
+pub enum Block {
+    Paragraph(TexStr<'static>),
+    Bullets(Vec<Bullet>),
+}
+
+/// Paragraphs and bullet lists describing an entry
+///
+/// Plain strings convert into a description with a single paragraph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(into = "DescriptionRepr")
+)]
+pub struct Description {
+    pub blocks: Vec<Block>,
+}
+
+impl Description {
+    /// Create an empty description
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a paragraph
+    pub fn paragraph(&mut self, text: impl Into<TexStr<'static>>) -> &mut Self {
+        self.blocks.push(Block::Paragraph(text.into()));
+
+        self
+    }
+
+    /// Add a bullet list
+    pub fn bullets<I, B>(&mut self, bullets: I) -> &mut Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Into<Bullet>,
+    {
+        let bullets = bullets.into_iter().map(Into::into).collect();
+        self.blocks.push(Block::Bullets(bullets));
+
+        self
+    }
+
+    /// Paragraphs of the description
+    pub fn paragraphs(&self) -> impl Iterator<Item = &TexStr<'static>> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Paragraph(text) => Some(text),
+            Block::Bullets(_) => None,
+        })
+    }
+
+    /// Bullets of the description in document order, nested bullets included
+    pub fn flat_bullets(&self) -> Vec<&TexStr<'static>> {
+        fn flatten<'b>(bullets: &'b [Bullet], texts: &mut Vec<&'b TexStr<'static>>) {
+            for bullet in bullets {
+                texts.push(&bullet.text);
+                flatten(&bullet.bullets, texts);
+            }
+        }
+
+        let mut texts = Vec::new();
+        for block in &self.blocks {
+            if let Block::Bullets(bullets) = block {
+                flatten(bullets, &mut texts);
+            }
+        }
+
+        texts
+    }
+
+    /// Render the description as a single argument
+    ///
+    /// A description with a single paragraph renders as that paragraph.
+    pub fn to_tex(&self) -> TexStr<'static> {
+        self.to_tex_with(&RenderOptions::default())
+    }
+
+    /// Render the description, reading plain text as Markdown if enabled in `options`
+    pub(crate) fn to_tex_with(&self, options: &RenderOptions) -> TexStr<'static> {
+        if let [Block::Paragraph(text)] = self.blocks.as_slice() {
+            return options.free_text(text).into_owned();
+        }
+
+        let mut s = String::new();
+        let mut after_paragraph = false;
+        for block in &self.blocks {
+            match block {
+                Block::Paragraph(text) => {
+                    if after_paragraph {
+                        s.push_str(r"\newline{}");
+                    }
+                    s.push_str(&options.free_text(text).to_string());
+                    after_paragraph = true;
+                }
+                Block::Bullets(bullets) if bullets.is_empty() => {}
+                Block::Bullets(bullets) => {
+                    let mut lines = Vec::new();
+                    render_bullets(bullets, 1, options, &mut lines);
+                    if !s.is_empty() {
+                        s.push('\n');
+                    }
+                    s.push_str(&lines.join("\n"));
+                    s.push('\n');
+                    after_paragraph = false;
+                }
+            }
+        }
+
+        TexStr::raw(s.trim_end().to_string())
+    }
+}
+
+impl From<&str> for Description {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
+    }
+}
+
+impl From<String> for Description {
+    fn from(text: String) -> Self {
+        TexStr::text(text).into()
+    }
+}
+
+impl From<TexStr<'static>> for Description {
+    fn from(text: TexStr<'static>) -> Self {
+        Self {
+            blocks: vec![Block::Paragraph(text)],
+        }
+    }
+}
+
+fn render_bullets(
+    bullets: &[Bullet],
+    depth: usize,
+    options: &RenderOptions,
+    lines: &mut Vec<String>,
+) {
+    lines.push(BEGIN_ITEMIZE.to_string());
+    for bullet in bullets {
+        bullet.render(depth, options, lines);
+    }
+    lines.push(END_ITEMIZE.to_string());
+}
+
+/// A bullet is a string, or `{ text, bullets }` with nested bullets
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum BulletRepr {
+    Text(TexStr<'static>),
+    Nested {
+        text: TexStr<'static>,
+        bullets: Vec<Bullet>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Bullet> for BulletRepr {
+    fn from(bullet: Bullet) -> Self {
+        if bullet.bullets.is_empty() {
+            Self::Text(bullet.text)
+        } else {
+            Self::Nested {
+                text: bullet.text,
+                bullets: bullet.bullets,
+            }
+        }
+    }
+}
+
+/// A block is a paragraph, or `{ bullets }` with a bullet list
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum BlockRepr {
+    Paragraph(TexStr<'static>),
+    Bullets { bullets: Vec<Bullet> },
+}
+
+#[cfg(feature = "serde")]
+impl From<Block> for BlockRepr {
+    fn from(block: Block) -> Self {
+        match block {
+            Block::Paragraph(text) => Self::Paragraph(text),
+            Block::Bullets(bullets) => Self::Bullets { bullets },
+        }
+    }
+}
+
+/// A description is a single paragraph, or a list of blocks
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum DescriptionRepr {
+    Paragraph(TexStr<'static>),
+    Blocks(Vec<Block>),
+}
+
+#[cfg(feature = "serde")]
+impl From<Description> for DescriptionRepr {
+    fn from(description: Description) -> Self {
+        if let [Block::Paragraph(text)] = description.blocks.as_slice() {
+            return Self::Paragraph(text.clone());
+        }
+
+        Self::Blocks(description.blocks)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bullet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BulletVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BulletVisitor {
+            type Value = Bullet;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "a bullet as text, or `{{ text, bullets }}`")
+            }
+
+            visit_text!(Bullet::new);
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                const FIELDS: &[&str] = &["text", "bullets", "raw"];
+                let (mut text, mut bullets) = (None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "text" | "raw" if text.is_some() => {
+                            return Err(A::Error::duplicate_field("text"))
+                        }
+                        "text" => text = Some(map.next_value()?),
+                        "raw" => text = Some(TexStr::raw(map.next_value::<String>()?)),
+                        "bullets" if bullets.is_some() => {
+                            return Err(A::Error::duplicate_field("bullets"))
+                        }
+                        "bullets" => bullets = Some(map.next_value()?),
+                        _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+                    }
+                }
+
+                Ok(Bullet {
+                    text: text.ok_or_else(|| A::Error::missing_field("text"))?,
+                    bullets: bullets.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_any(BulletVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Block {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BlockVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BlockVisitor {
+            type Value = Block;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "a paragraph as text, or a bullet list as `{{ bullets }}`"
+                )
+            }
+
+            visit_text!(Block::Paragraph);
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                let (block, fields): (_, &[&str]) = match map.next_key::<String>()?.as_deref() {
+                    Some("bullets") => (Block::Bullets(map.next_value()?), &["bullets"]),
+                    Some("raw") => (
+                        Block::Paragraph(TexStr::raw(map.next_value::<String>()?)),
+                        &["raw"],
+                    ),
+                    Some(key) => return Err(A::Error::unknown_field(key, &["bullets", "raw"])),
+                    None => return Err(A::Error::missing_field("bullets")),
+                };
+
+                if let Some(key) = map.next_key::<String>()? {
+                    return Err(A::Error::unknown_field(&key, fields));
+                }
+
+                Ok(block)
+            }
+        }
+
+        deserializer.deserialize_any(BlockVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Description {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DescriptionVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DescriptionVisitor {
+            type Value = Description;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "a description as text, or a list of paragraphs and bullet lists"
+                )
+            }
+
+            visit_text!(Description::from);
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Self::Value, A::Error> {
+                TexStrVisitor.visit_map(map).map(Description::from)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut blocks = Vec::new();
+                while let Some(block) = seq.next_element()? {
+                    blocks.push(block);
+                }
+
+                Ok(Description { blocks })
+            }
+        }
+
+        deserializer.deserialize_any(DescriptionVisitor)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Bullet {
+    fn schema_name() -> String {
+        "Bullet".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        BulletRepr::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Block {
+    fn schema_name() -> String {
+        "Block".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        BlockRepr::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Description {
+    fn schema_name() -> String {
+        "Description".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        DescriptionRepr::json_schema(gen)
+    }
+}
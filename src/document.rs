@@ -0,0 +1,127 @@
+use super::texify;
+use crate::section;
+use latex::{Document, Element};
+
+/// A trait which implement useful functions for the body of moderncv documents
+pub trait CVDocument {
+    fn makecvtitle(&mut self) -> &mut Self;
+    fn makecvtitle_at_start(&mut self) -> &mut Self;
+    fn makecvfooter(&mut self) -> &mut Self;
+    fn closesection(&mut self) -> &mut Self;
+    fn emptysection(&mut self) -> &mut Self;
+    fn pagebreak(&mut self) -> &mut Self;
+    fn newpage(&mut self) -> &mut Self;
+    fn vspace(&mut self, length: &str) -> &mut Self;
+}
+
+impl CVDocument for Document {
+    /// Print the resume header here (`\makecvtitle`)
+    ///
+    /// Use it instead of `Element::TitlePage`, which renders as `\maketitle`.
+    fn makecvtitle(&mut self) -> &mut Self {
+        let elem = self::makecvtitle();
+        self.push(elem);
+
+        self
+    }
+
+    /// Print the resume header before the elements already in the document
+    fn makecvtitle_at_start(&mut self) -> &mut Self {
+        let mut doc = Document::new(self.class.clone());
+        doc.preamble = self.preamble.clone();
+        doc.makecvtitle().push_doc(self);
+        *self = doc;
+
+        self
+    }
+
+    /// Print the footer with the personal information (`\makecvfooter`)
+    fn makecvfooter(&mut self) -> &mut Self {
+        let elem = self::makecvfooter();
+        self.push(elem);
+
+        self
+    }
+
+    /// Close the current section
+    fn closesection(&mut self) -> &mut Self {
+        let elem = self::closesection();
+        self.push(elem);
+
+        self
+    }
+
+    /// Add an empty section
+    fn emptysection(&mut self) -> &mut Self {
+        let elem = self::emptysection();
+        self.push(elem);
+
+        self
+    }
+
+    /// Break the page after the current line
+    fn pagebreak(&mut self) -> &mut Self {
+        let elem = section::pagebreak();
+        self.push(elem);
+
+        self
+    }
+
+    /// Start a new page
+    fn newpage(&mut self) -> &mut Self {
+        let elem = section::newpage();
+        self.push(elem);
+
+        self
+    }
+
+    /// Add vertical space, `length` is a LaTeX length like `-0.5em`
+    fn vspace(&mut self, length: &str) -> &mut Self {
+        let elem = section::vspace(length);
+        self.push(elem);
+
+        self
+    }
+}
+
+/// Where `Resume::to_document_with_title()` prints the resume header
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TitlePlacement {
+    /// Before the first section
+    #[default]
+    Start,
+    /// Before the section with this index, at the end if there is none
+    BeforeSection(usize),
+    /// Nowhere, call `CVDocument::makecvtitle()` yourself
+    Manual,
+}
+
+/// Print the resume header(`\makecvtitle`)
+pub fn makecvtitle() -> Element {
+    let s = texify!("makecvtitle");
+
+    Element::UserDefined(s)
+}
+
+/// Print the footer(`\makecvfooter`)
+pub fn makecvfooter() -> Element {
+    let s = texify!("makecvfooter");
+
+    Element::UserDefined(s)
+}
+
+/// Close section(`\closesection{}`)
+pub fn closesection() -> Element {
+    let empty = "";
+    let s = texify!("closesection", empty);
+
+    Element::UserDefined(s)
+}
+
+/// Empty section(`\emptysection{}`)
+pub fn emptysection() -> Element {
+    let empty = "";
+    let s = texify!("emptysection", empty);
+
+    Element::UserDefined(s)
+}
@@ -0,0 +1,191 @@
+//! Font selection for the target engine
+//!
+//! pdflatex uses font packages, xelatex and lualatex load system fonts with
+//! fontspec.
+//!
+//! ```
+//! use latex::Document;
+//! use moderncv::fonts::Fonts;
+//! use moderncv::{document_class, CVPreamble, Engine};
+//!
+//! let mut doc = Document::new(document_class());
+//! doc.preamble
+//!     .fonts(Fonts::new(Engine::Lualatex).main_font("TeX Gyre Pagella", &["Ligatures=TeX"]))
+//!     .unwrap();
+//! ```
+
+use crate::{texify, Engine};
+use latex::PreambleElement;
+
+/// Font packages for pdflatex
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FontPackage {
+    /// `lmodern`
+    LatinModern,
+    /// `charter`
+    Charter,
+    /// `mathpazo`
+    Palatino,
+    /// `mathptmx`
+    Times,
+    /// `helvet`
+    Helvetica,
+    /// `libertine`
+    Libertine,
+    /// `sourcesanspro`
+    SourceSansPro,
+    /// Any other package
+    Other(String),
+}
+
+impl std::fmt::Display for FontPackage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LatinModern => write!(f, "lmodern"),
+            Self::Charter => write!(f, "charter"),
+            Self::Palatino => write!(f, "mathpazo"),
+            Self::Times => write!(f, "mathptmx"),
+            Self::Helvetica => write!(f, "helvet"),
+            Self::Libertine => write!(f, "libertine"),
+            Self::SourceSansPro => write!(f, "sourcesanspro"),
+            Self::Other(package) => write!(f, "{}", package),
+        }
+    }
+}
+
+/// Errors of fonts which the engine cannot load
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontError {
+    /// fontspec only works with xelatex and lualatex
+    FontspecUnsupported(Engine),
+}
+
+impl std::fmt::Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FontspecUnsupported(engine) => write!(
+                f,
+                "{} cannot load system fonts with fontspec, use a font package",
+                engine
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+/// A system font with fontspec options
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SystemFont {
+    name: String,
+    options: Vec<String>,
+}
+
+impl SystemFont {
+    fn to_element(&self, command: &str) -> PreambleElement {
+        let name = &self.name;
+        let options = if self.options.is_empty() {
+            None
+        } else {
+            Some(self.options.join(","))
+        };
+
+        PreambleElement::UserDefined(texify!(command, [options], name))
+    }
+}
+
+/// Fonts of a document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fonts {
+    engine: Engine,
+    packages: Vec<FontPackage>,
+    main: Option<SystemFont>,
+    sans: Option<SystemFont>,
+    mono: Option<SystemFont>,
+}
+
+impl Fonts {
+    /// Create an empty font configuration for `engine`
+    pub fn new(engine: Engine) -> Self {
+        Self {
+            engine,
+            packages: Vec::new(),
+            main: None,
+            sans: None,
+            mono: None,
+        }
+    }
+
+    /// Load a font package
+    pub fn package(&mut self, package: FontPackage) -> &mut Self {
+        self.packages.push(package);
+
+        self
+    }
+
+    /// Set the main font with fontspec (`\setmainfont`)
+    pub fn main_font(&mut self, name: &str, options: &[&str]) -> &mut Self {
+        self.main = Some(system_font(name, options));
+
+        self
+    }
+
+    /// Set the sans serif font with fontspec (`\setsansfont`)
+    pub fn sans_font(&mut self, name: &str, options: &[&str]) -> &mut Self {
+        self.sans = Some(system_font(name, options));
+
+        self
+    }
+
+    /// Set the monospaced font with fontspec (`\setmonofont`)
+    pub fn mono_font(&mut self, name: &str, options: &[&str]) -> &mut Self {
+        self.mono = Some(system_font(name, options));
+
+        self
+    }
+
+    /// The preamble elements, fontspec fonts are refused for pdflatex
+    pub fn to_elements(&self) -> Result<Vec<PreambleElement>, FontError> {
+        let mut elements = self
+            .packages
+            .iter()
+            .map(|package| PreambleElement::UsePackage {
+                package: package.to_string(),
+                argument: None,
+            })
+            .collect::<Vec<_>>();
+
+        let system_fonts = [
+            ("setmainfont", &self.main),
+            ("setsansfont", &self.sans),
+            ("setmonofont", &self.mono),
+        ];
+        if system_fonts.iter().all(|(_, font)| font.is_none()) {
+            return Ok(elements);
+        }
+
+        if !self.engine.is_unicode() {
+            return Err(FontError::FontspecUnsupported(self.engine));
+        }
+
+        elements.push(PreambleElement::UsePackage {
+            package: "fontspec".to_string(),
+            argument: None,
+        });
+
+        for (command, font) in &system_fonts {
+            if let Some(font) = font {
+                elements.push(font.to_element(command));
+            }
+        }
+
+        Ok(elements)
+    }
+}
+
+fn system_font(name: &str, options: &[&str]) -> SystemFont {
+    SystemFont {
+        name: name.to_string(),
+        options: options.iter().map(|option| option.to_string()).collect(),
+    }
+}
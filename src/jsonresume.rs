@@ -0,0 +1,1062 @@
+use crate::date::{End, Years};
+use crate::description::Description;
+use crate::preamble::SocialType;
+use crate::resume::{
+    Address, CVEntry, Contact, Personal, Phone, Photo, Resume, ResumeSection, Social,
+};
+use crate::tex::{unescape, TexStr};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A [JSON Resume](https://jsonresume.org/schema/) document
+///
+/// Fields without a moderncv counterpart are kept in `other`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonResume {
+    pub basics: Basics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub volunteer: Vec<Volunteer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<Education>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub awards: Vec<Award>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<Certificate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<Publication>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<Skill>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<Language>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub interests: Vec<Interest>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<Project>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `basics`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Basics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<Profile>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `basics.location`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `basics.profiles[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub network: String,
+    pub username: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `work[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Work {
+    #[serde(alias = "company")]
+    pub name: String,
+    pub position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `volunteer[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Volunteer {
+    pub organization: String,
+    pub position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `education[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Education {
+    pub institution: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub courses: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `awards[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Award {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awarder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `certificates[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Certificate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `publications[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Publication {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `skills[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Skill {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `languages[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Language {
+    pub language: String,
+    pub fluency: String,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `interests[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Interest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `references[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reference {
+    pub name: String,
+    pub reference: String,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `projects[]`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Project {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// Something in a JSON Resume document which could not be mapped to moderncv
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// A profile whose network has no matching `SocialType`
+    UnknownNetwork { path: String, network: String },
+    /// A field without a moderncv counterpart
+    UnsupportedField { path: String },
+    /// An image which is not a local file
+    RemoteImage { path: String, url: String },
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownNetwork { path, network } => {
+                write!(
+                    f,
+                    "{}: unknown network `{}`, profile skipped",
+                    path, network
+                )
+            }
+            Self::UnsupportedField { path } => write!(f, "{}: unsupported field skipped", path),
+            Self::RemoteImage { path, url } => {
+                write!(f, "{}: remote image `{}` skipped", path, url)
+            }
+        }
+    }
+}
+
+/// A resume imported from JSON Resume, with everything that was skipped
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Import {
+    pub resume: Resume,
+    pub warnings: Vec<Warning>,
+}
+
+/// Import a JSON Resume document
+pub fn import(json: &str) -> serde_json::Result<Import> {
+    let json_resume: JsonResume = serde_json::from_str(json)?;
+
+    Ok(json_resume.to_resume())
+}
+
+/// Export a resume as a JSON Resume document
+pub fn export(resume: &Resume) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&JsonResume::from_resume(resume))
+}
+
+impl JsonResume {
+    /// Map the document onto the resume model
+    pub fn to_resume(&self) -> Import {
+        let mut importer = Importer::default();
+        let resume = importer.resume(self);
+
+        Import {
+            resume,
+            warnings: importer.warnings,
+        }
+    }
+
+    /// Build a document from the resume model
+    ///
+    /// Sections are mapped by title (`Education`, `Skills`, `Languages`, ...),
+    /// `\cventry` entries of any other section become `work` items.
+    pub fn from_resume(resume: &Resume) -> Self {
+        let mut json = Self {
+            basics: basics(resume),
+            ..Self::default()
+        };
+
+        for section in &resume.sections {
+            let kind = SectionKind::from_title(&section.title);
+            for entry in &section.entries {
+                json.push_entry(kind, entry);
+            }
+        }
+
+        json
+    }
+
+    fn push_entry(&mut self, kind: SectionKind, entry: &CVEntry) {
+        match entry {
+            CVEntry::Entry {
+                years,
+                job,
+                employer,
+                localization,
+                grade,
+                comment,
+            } => {
+                let (start_date, end_date) = match dates(years) {
+                    Some((start, end)) => (Some(start), end),
+                    None => (None, None),
+                };
+                let date = start_date.clone();
+                let (mut summary, highlights) = summary(comment);
+
+                // Years which are not dates are kept in the summary
+                if let (None, Years::Text(text)) = (&start_date, years) {
+                    if let Some(years) = non_empty(plain(text)) {
+                        summary = Some(match summary {
+                            Some(summary) => format!("{}\n{}", years, summary),
+                            None => years,
+                        });
+                    }
+                }
+                let (job, employer) = (plain(job), plain(employer));
+
+                match kind {
+                    SectionKind::Education => self.education.push(Education {
+                        institution: employer,
+                        study_type: non_empty(job),
+                        start_date,
+                        end_date,
+                        score: grade.as_ref().map(plain),
+                        courses: summary.into_iter().collect(),
+                        ..Education::default()
+                    }),
+                    SectionKind::Volunteer => self.volunteer.push(Volunteer {
+                        organization: employer,
+                        position: job,
+                        start_date,
+                        end_date,
+                        summary,
+                        highlights,
+                        ..Volunteer::default()
+                    }),
+                    SectionKind::Awards => self.awards.push(Award {
+                        title: job,
+                        date,
+                        awarder: non_empty(employer),
+                        summary,
+                        ..Award::default()
+                    }),
+                    SectionKind::Certificates => self.certificates.push(Certificate {
+                        name: job,
+                        date,
+                        issuer: non_empty(employer),
+                        ..Certificate::default()
+                    }),
+                    SectionKind::Publications => self.publications.push(Publication {
+                        name: job,
+                        publisher: non_empty(employer),
+                        release_date: date,
+                        summary,
+                        ..Publication::default()
+                    }),
+                    SectionKind::Projects => self.projects.push(Project {
+                        name: job,
+                        entity: non_empty(employer),
+                        start_date,
+                        end_date,
+                        description: summary,
+                        highlights,
+                        ..Project::default()
+                    }),
+                    _ => self.work.push(Work {
+                        name: employer,
+                        position: job,
+                        location: non_empty(plain(localization)),
+                        start_date,
+                        end_date,
+                        summary,
+                        highlights,
+                        ..Work::default()
+                    }),
+                }
+            }
+            CVEntry::Language {
+                name,
+                level,
+                comment: _,
+            } => self.languages.push(Language {
+                language: plain(name),
+                fluency: plain(level),
+                ..Language::default()
+            }),
+            CVEntry::Computer {
+                category1,
+                programs1,
+                category2,
+                programs2,
+            } => {
+                self.push_item(kind, category1, programs1);
+                self.push_item(kind, category2, programs2);
+            }
+            CVEntry::Line { leftmark, text } => self.push_item(kind, leftmark, text),
+            CVEntry::Item { header, text } => self.push_item(kind, header, text),
+            CVEntry::ItemWithComment { header, text, .. } => self.push_item(kind, header, text),
+            CVEntry::DoubleItem {
+                header1,
+                text1,
+                header2,
+                text2,
+            } => {
+                self.push_item(kind, header1, text1);
+                self.push_item(kind, header2, text2);
+            }
+            CVEntry::ListItem { item } => self.push_item(kind, item, &TexStr::default()),
+            CVEntry::ListDoubleItem { item1, item2 } => {
+                self.push_item(kind, item1, &TexStr::default());
+                self.push_item(kind, item2, &TexStr::default());
+            }
+            CVEntry::Subsection { entries, .. } => {
+                for entry in entries {
+                    self.push_entry(kind, entry);
+                }
+            }
+            CVEntry::PageBreak | CVEntry::NewPage | CVEntry::VSpace { .. } => {}
+        }
+    }
+
+    fn push_item(&mut self, kind: SectionKind, header: &TexStr, text: &TexStr) {
+        let (name, text) = (plain(header), plain(text));
+        if name.is_empty() && text.is_empty() {
+            return;
+        }
+
+        match kind {
+            SectionKind::References => self.references.push(Reference {
+                name,
+                reference: text,
+                ..Reference::default()
+            }),
+            SectionKind::Interests => self.interests.push(Interest {
+                name,
+                keywords: keywords(&text),
+                ..Interest::default()
+            }),
+            _ => {
+                let (text, level) = match text.strip_suffix(')').and_then(|t| t.rsplit_once(" (")) {
+                    Some((keywords, level)) => (keywords.to_string(), Some(level.to_string())),
+                    None => (text, None),
+                };
+
+                self.skills.push(Skill {
+                    name,
+                    level,
+                    keywords: keywords(&text),
+                    ..Skill::default()
+                })
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Importer {
+    warnings: Vec<Warning>,
+}
+
+impl Importer {
+    fn unsupported(&mut self, path: &str, other: &Map<String, Value>) {
+        for (key, value) in other {
+            let empty = match value {
+                Value::Null => true,
+                Value::String(s) => s.is_empty(),
+                Value::Array(a) => a.is_empty(),
+                Value::Object(o) => o.is_empty(),
+                _ => false,
+            };
+
+            if !empty {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                self.warnings.push(Warning::UnsupportedField { path });
+            }
+        }
+    }
+
+    fn resume(&mut self, json: &JsonResume) -> Resume {
+        self.unsupported("", &json.other);
+
+        let mut resume = Resume::new();
+        self.basics(&json.basics, &mut resume);
+
+        let mut section = ResumeSection::new("Experience");
+        for (i, work) in json.work.iter().enumerate() {
+            self.unsupported(&format!("work[{}]", i), &work.other);
+            section.push(CVEntry::Entry {
+                years: years(&work.start_date, &work.end_date),
+                job: work.position.clone().into(),
+                employer: work.name.clone().into(),
+                localization: text(&work.location),
+                grade: None,
+                comment: description(&work.summary, &work.highlights),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Volunteering");
+        for (i, volunteer) in json.volunteer.iter().enumerate() {
+            self.unsupported(&format!("volunteer[{}]", i), &volunteer.other);
+            section.push(CVEntry::Entry {
+                years: years(&volunteer.start_date, &volunteer.end_date),
+                job: volunteer.position.clone().into(),
+                employer: volunteer.organization.clone().into(),
+                localization: TexStr::default(),
+                grade: None,
+                comment: description(&volunteer.summary, &volunteer.highlights),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Education");
+        for (i, education) in json.education.iter().enumerate() {
+            self.unsupported(&format!("education[{}]", i), &education.other);
+            let degree = match (&education.study_type, &education.area) {
+                (Some(study_type), Some(area)) => format!("{} in {}", study_type, area),
+                (Some(degree), None) | (None, Some(degree)) => degree.clone(),
+                (None, None) => String::new(),
+            };
+            let courses = if education.courses.is_empty() {
+                None
+            } else {
+                Some(education.courses.join(", ").into())
+            };
+
+            section.push(CVEntry::Entry {
+                years: years(&education.start_date, &education.end_date),
+                job: degree.into(),
+                employer: education.institution.clone().into(),
+                localization: TexStr::default(),
+                grade: education.score.clone().map(TexStr::from),
+                comment: courses,
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Awards");
+        for (i, award) in json.awards.iter().enumerate() {
+            self.unsupported(&format!("awards[{}]", i), &award.other);
+            section.push(CVEntry::Entry {
+                years: text(&award.date).into(),
+                job: award.title.clone().into(),
+                employer: text(&award.awarder),
+                localization: TexStr::default(),
+                grade: None,
+                comment: award.summary.clone().map(Description::from),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Certificates");
+        for (i, certificate) in json.certificates.iter().enumerate() {
+            self.unsupported(&format!("certificates[{}]", i), &certificate.other);
+            section.push(CVEntry::Entry {
+                years: text(&certificate.date).into(),
+                job: certificate.name.clone().into(),
+                employer: text(&certificate.issuer),
+                localization: TexStr::default(),
+                grade: None,
+                comment: None,
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Publications");
+        for (i, publication) in json.publications.iter().enumerate() {
+            self.unsupported(&format!("publications[{}]", i), &publication.other);
+            section.push(CVEntry::Entry {
+                years: text(&publication.release_date).into(),
+                job: publication.name.clone().into(),
+                employer: text(&publication.publisher),
+                localization: TexStr::default(),
+                grade: None,
+                comment: publication.summary.clone().map(Description::from),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Projects");
+        for (i, project) in json.projects.iter().enumerate() {
+            self.unsupported(&format!("projects[{}]", i), &project.other);
+            section.push(CVEntry::Entry {
+                years: years(&project.start_date, &project.end_date),
+                job: project.name.clone().into(),
+                employer: text(&project.entity),
+                localization: TexStr::default(),
+                grade: None,
+                comment: description(&project.description, &project.highlights),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Skills");
+        for (i, skill) in json.skills.iter().enumerate() {
+            self.unsupported(&format!("skills[{}]", i), &skill.other);
+        }
+        for pair in json.skills.chunks(2) {
+            let entry = match pair {
+                [first, second] => CVEntry::DoubleItem {
+                    header1: first.name.clone().into(),
+                    text1: skill_text(first),
+                    header2: second.name.clone().into(),
+                    text2: skill_text(second),
+                },
+                [skill] => CVEntry::Item {
+                    header: skill.name.clone().into(),
+                    text: skill_text(skill),
+                },
+                _ => unreachable!(),
+            };
+            section.push(entry);
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Languages");
+        for (i, language) in json.languages.iter().enumerate() {
+            self.unsupported(&format!("languages[{}]", i), &language.other);
+            section.push(CVEntry::Language {
+                name: language.language.clone().into(),
+                level: language.fluency.clone().into(),
+                comment: TexStr::default(),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("Interests");
+        for (i, interest) in json.interests.iter().enumerate() {
+            self.unsupported(&format!("interests[{}]", i), &interest.other);
+            section.push(CVEntry::Item {
+                header: interest.name.clone().into(),
+                text: interest.keywords.join(", ").into(),
+            });
+        }
+        push_section(&mut resume, section);
+
+        let mut section = ResumeSection::new("References");
+        for (i, reference) in json.references.iter().enumerate() {
+            self.unsupported(&format!("references[{}]", i), &reference.other);
+            section.push(CVEntry::Item {
+                header: reference.name.clone().into(),
+                text: reference.reference.clone().into(),
+            });
+        }
+        push_section(&mut resume, section);
+
+        resume
+    }
+
+    fn basics(&mut self, basics: &Basics, resume: &mut Resume) {
+        self.unsupported("basics", &basics.other);
+
+        resume.title = basics.label.clone();
+
+        let name = basics.name.as_deref().unwrap_or_default().trim();
+        let (firstname, lastname) = match name.rfind(' ') {
+            Some(i) => (name[..i].trim_end(), &name[i + 1..]),
+            None => (name, ""),
+        };
+
+        let photo = match &basics.image {
+            Some(image) if image.contains("://") => {
+                self.warnings.push(Warning::RemoteImage {
+                    path: "basics.image".to_string(),
+                    url: image.clone(),
+                });
+                None
+            }
+            Some(image) if !image.is_empty() => Some(Photo {
+                file: image.clone(),
+                width: None,
+                frame: None,
+            }),
+            _ => None,
+        };
+
+        resume.personal = Personal {
+            firstname: firstname.to_string().into(),
+            lastname: lastname.to_string().into(),
+            photo,
+            quote: basics.summary.clone().map(TexStr::from),
+            extrainfo: None,
+        };
+
+        let mut contact = Contact {
+            email: basics.email.clone().map(TexStr::from),
+            homepage: basics.url.as_deref().map(|url| {
+                let url = url
+                    .trim_start_matches("https://")
+                    .trim_start_matches("http://");
+                url.to_string().into()
+            }),
+            ..Contact::default()
+        };
+
+        if let Some(phone) = &basics.phone {
+            contact.phones.push(Phone {
+                number: phone.clone().into(),
+                phone_type: None,
+            });
+        }
+
+        if let Some(location) = &basics.location {
+            self.unsupported("basics.location", &location.other);
+            contact.address = address(location);
+        }
+
+        for (i, profile) in basics.profiles.iter().enumerate() {
+            let path = format!("basics.profiles[{}]", i);
+            self.unsupported(&path, &profile.other);
+
+            match social_type(&profile.network) {
+                Some(social_type) => contact.socials.push(Social {
+                    account: profile.username.clone().into(),
+                    social_type,
+                    url: profile.url.clone().map(TexStr::from),
+                }),
+                None => self.warnings.push(Warning::UnknownNetwork {
+                    path,
+                    network: profile.network.clone(),
+                }),
+            }
+        }
+
+        resume.contact = contact;
+    }
+}
+
+/// Match a JSON Resume network name against the moderncv social types
+fn social_type(network: &str) -> Option<SocialType> {
+    let network: String = network
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    let social_type = match network.as_str() {
+        "linkedin" => SocialType::LinkedIn,
+        "xing" => SocialType::XING,
+        "twitter" => SocialType::Twitter,
+        "github" => SocialType::Github,
+        "gitlab" => SocialType::Gitlab,
+        "stackoverflow" => SocialType::StackOverflow,
+        "bitbucket" => SocialType::Bitbucket,
+        "skype" => SocialType::Skype,
+        "orcid" => SocialType::ORCID,
+        "researchgate" => SocialType::ResearchGate,
+        "researcherid" => SocialType::ResearcherID,
+        "telegram" => SocialType::Telegram,
+        "googlescholar" => SocialType::GoogleScholar,
+        _ => return None,
+    };
+
+    Some(social_type)
+}
+
+fn address(location: &Location) -> Option<Address> {
+    let join = |parts: &[&Option<String>], sep: &str| {
+        let parts: Vec<&str> = parts
+            .iter()
+            .filter_map(|part| part.as_deref())
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(sep))
+        }
+    };
+
+    let street = join(&[&location.address], "").map(|street| street.replace('\n', ", "));
+    let city = join(&[&location.postal_code, &location.city], " ");
+    let country = join(&[&location.region, &location.country_code], ", ");
+
+    if street.is_none() && city.is_none() && country.is_none() {
+        return None;
+    }
+
+    Some(Address {
+        street: street.unwrap_or_default().into(),
+        city: city.map(TexStr::from),
+        country: country.map(TexStr::from),
+    })
+}
+
+fn push_section(resume: &mut Resume, section: ResumeSection) {
+    if !section.entries.is_empty() {
+        resume.push(section);
+    }
+}
+
+fn text(s: &Option<String>) -> TexStr<'static> {
+    s.clone().unwrap_or_default().into()
+}
+
+/// JSON Resume leaves `endDate` out for current positions
+fn years(start: &Option<String>, end: &Option<String>) -> Years {
+    let years = match (start, end) {
+        (Some(start), Some(end)) if start == end => start.clone(),
+        (Some(start), Some(end)) => format!("{}--{}", start, end),
+        (Some(start), None) => format!("{}--present", start),
+        (None, Some(end)) => end.clone(),
+        (None, None) => String::new(),
+    };
+
+    years.into()
+}
+
+/// Summary followed by a bullet list of the highlights
+fn description(summary: &Option<String>, highlights: &[String]) -> Option<Description> {
+    if highlights.is_empty() {
+        return summary.clone().map(Description::from);
+    }
+
+    let mut description = Description::new();
+    if let Some(summary) = summary {
+        description.paragraph(summary.clone());
+    }
+    description.bullets(highlights.iter().cloned());
+
+    Some(description)
+}
+
+fn skill_text(skill: &Skill) -> TexStr<'static> {
+    let keywords = skill.keywords.join(", ");
+    let text = match skill.level.as_deref() {
+        Some(level) if !level.is_empty() && !keywords.is_empty() => {
+            format!("{} ({})", keywords, level)
+        }
+        Some(level) if !level.is_empty() => level.to_string(),
+        _ => keywords,
+    };
+
+    text.into()
+}
+
+/// JSON Resume section a resume section maps onto
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SectionKind {
+    Work,
+    Volunteer,
+    Education,
+    Awards,
+    Certificates,
+    Publications,
+    Projects,
+    Skills,
+    Interests,
+    References,
+}
+
+impl SectionKind {
+    fn from_title(title: &str) -> Self {
+        let title = title.to_lowercase();
+        let kinds = [
+            ("volunteer", Self::Volunteer),
+            ("education", Self::Education),
+            ("award", Self::Awards),
+            ("certificat", Self::Certificates),
+            ("publication", Self::Publications),
+            ("project", Self::Projects),
+            ("skill", Self::Skills),
+            ("interest", Self::Interests),
+            ("reference", Self::References),
+        ];
+
+        kinds
+            .iter()
+            .find(|(name, _)| title.contains(name))
+            .map_or(Self::Work, |(_, kind)| *kind)
+    }
+}
+
+fn basics(resume: &Resume) -> Basics {
+    let personal = &resume.personal;
+    let contact = &resume.contact;
+
+    let name = format!(
+        "{} {}",
+        plain(&personal.firstname),
+        plain(&personal.lastname)
+    );
+
+    let location = contact.address.as_ref().map(|address| Location {
+        address: non_empty(plain(&address.street)),
+        city: address.city.as_ref().map(plain),
+        country_code: address.country.as_ref().map(plain),
+        ..Location::default()
+    });
+
+    let url = contact.homepage.as_ref().map(plain).map(|url| {
+        if url.contains("://") {
+            url
+        } else {
+            format!("https://{}", url)
+        }
+    });
+
+    let profiles = contact
+        .socials
+        .iter()
+        .map(|social| Profile {
+            network: network(social.social_type).to_string(),
+            username: plain(&social.account),
+            url: social.url.as_ref().map(plain),
+            ..Profile::default()
+        })
+        .collect();
+
+    Basics {
+        name: non_empty(name.trim().to_string()),
+        label: resume.title.clone(),
+        image: personal.photo.as_ref().map(|photo| photo.file.clone()),
+        email: contact.email.as_ref().map(plain),
+        phone: contact.phones.first().map(|phone| plain(&phone.number)),
+        url,
+        summary: personal.quote.as_ref().map(plain),
+        location,
+        profiles,
+        ..Basics::default()
+    }
+}
+
+/// JSON Resume network name of a social type
+fn network(social_type: SocialType) -> &'static str {
+    match social_type {
+        SocialType::LinkedIn => "LinkedIn",
+        SocialType::XING => "XING",
+        SocialType::Twitter => "Twitter",
+        SocialType::Github => "GitHub",
+        SocialType::Gitlab => "GitLab",
+        SocialType::StackOverflow => "Stack Overflow",
+        SocialType::Bitbucket => "Bitbucket",
+        SocialType::Skype => "Skype",
+        SocialType::ORCID => "ORCID",
+        SocialType::ResearchGate => "ResearchGate",
+        SocialType::ResearcherID => "ResearcherID",
+        SocialType::Telegram => "Telegram",
+        SocialType::GoogleScholar => "Google Scholar",
+    }
+}
+
+/// Plain text of an argument, raw LaTeX is unescaped
+fn plain(s: &TexStr) -> String {
+    match s {
+        TexStr::Text(s) => s.to_string(),
+        TexStr::Raw(s) => unescape(s).into_owned(),
+    }
+}
+
+fn non_empty(s: String) -> Option<String> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+fn keywords(text: &str) -> Vec<String> {
+    text.split(", ")
+        .filter(|keyword| !keyword.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// JSON Resume dates of `years`, a single date is both start and end
+///
+/// `None` if the years are not a date range.
+fn dates(years: &Years) -> Option<(String, Option<String>)> {
+    let range = years.range()?;
+    let end = match range.end {
+        None => Some(range.start.to_string()),
+        Some(End::Date(end)) => Some(end.to_string()),
+        Some(End::Present) => None,
+    };
+
+    Some((range.start.to_string(), end))
+}
+
+/// Split a description into summary and highlights
+fn summary(comment: &Option<Description>) -> (Option<String>, Vec<String>) {
+    let comment = match comment {
+        Some(comment) => comment,
+        None => return (None, Vec::new()),
+    };
+
+    // Raw paragraphs may still hold `\newline{}` separated highlights
+    let mut lines = comment.paragraphs().flat_map(|paragraph| match paragraph {
+        TexStr::Raw(s) => s
+            .split(r"\newline{}")
+            .map(|line| unescape(line).into_owned())
+            .collect(),
+        text => vec![plain(text)],
+    });
+    let summary = lines.next().and_then(non_empty);
+    let highlights = lines
+        .chain(comment.flat_bullets().into_iter().map(plain))
+        .collect();
+
+    (summary, highlights)
+}
@@ -0,0 +1,207 @@
+use super::texify;
+use crate::tex::TexStr;
+use latex::{Document, Element};
+
+/// A trait which implement useful functions for moderncv cover letters
+pub trait CVLetter {
+    fn recipient<'a>(
+        &mut self,
+        name: impl Into<TexStr<'a>>,
+        address: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn date<'a>(&mut self, date: impl Into<TexStr<'a>>) -> &mut Self;
+    fn opening<'a>(&mut self, opening: impl Into<TexStr<'a>>) -> &mut Self;
+    fn closing<'a>(&mut self, closing: impl Into<TexStr<'a>>) -> &mut Self;
+    fn enclosure<'a>(
+        &mut self,
+        label: Option<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn makelettertitle(&mut self) -> &mut Self;
+    fn makeletterclosing(&mut self) -> &mut Self;
+}
+
+impl CVLetter for Document {
+    /// Set the recipient
+    fn recipient<'a>(
+        &mut self,
+        name: impl Into<TexStr<'a>>,
+        address: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::recipient(name, address);
+        self.push(elem);
+
+        self
+    }
+
+    /// Set the date of the letter
+    fn date<'a>(&mut self, date: impl Into<TexStr<'a>>) -> &mut Self {
+        let elem = self::date(date);
+        self.push(elem);
+
+        self
+    }
+
+    /// Set the opening salutation
+    fn opening<'a>(&mut self, opening: impl Into<TexStr<'a>>) -> &mut Self {
+        let elem = self::opening(opening);
+        self.push(elem);
+
+        self
+    }
+
+    /// Set the closing salutation
+    fn closing<'a>(&mut self, closing: impl Into<TexStr<'a>>) -> &mut Self {
+        let elem = self::closing(closing);
+        self.push(elem);
+
+        self
+    }
+
+    /// Set the enclosures
+    fn enclosure<'a>(
+        &mut self,
+        label: Option<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::enclosure(label, text);
+        self.push(elem);
+
+        self
+    }
+
+    /// Print the letter header
+    fn makelettertitle(&mut self) -> &mut Self {
+        let elem = self::makelettertitle();
+        self.push(elem);
+
+        self
+    }
+
+    /// Print the closing, the signature and the enclosures
+    fn makeletterclosing(&mut self) -> &mut Self {
+        let elem = self::makeletterclosing();
+        self.push(elem);
+
+        self
+    }
+}
+
+/// Set the recipient, address lines are separated by `\\`
+pub fn recipient<'a>(name: impl Into<TexStr<'a>>, address: impl Into<TexStr<'a>>) -> Element {
+    let (name, address) = (name.into(), address.into());
+    let s = texify!("recipient", name, address);
+    Element::UserDefined(s)
+}
+
+/// Set the date of the letter, `\today` when not set
+pub fn date<'a>(date: impl Into<TexStr<'a>>) -> Element {
+    let date = date.into();
+    let s = texify!("date", date);
+    Element::UserDefined(s)
+}
+
+/// Set the opening salutation
+pub fn opening<'a>(opening: impl Into<TexStr<'a>>) -> Element {
+    let opening = opening.into();
+    let s = texify!("opening", opening);
+    Element::UserDefined(s)
+}
+
+/// Set the closing salutation
+pub fn closing<'a>(closing: impl Into<TexStr<'a>>) -> Element {
+    let closing = closing.into();
+    let s = texify!("closing", closing);
+    Element::UserDefined(s)
+}
+
+/// Set the enclosures, the label defaults to "Enclosure"
+pub fn enclosure<'a>(label: Option<TexStr<'a>>, text: impl Into<TexStr<'a>>) -> Element {
+    let text = text.into();
+    let s = texify!("enclosure", [label], text);
+    Element::UserDefined(s)
+}
+
+/// Print the letter header(`\makelettertitle`)
+pub fn makelettertitle() -> Element {
+    Element::UserDefined(texify!("makelettertitle"))
+}
+
+/// Print the closing, the signature and the enclosures(`\makeletterclosing`)
+pub fn makeletterclosing() -> Element {
+    Element::UserDefined(texify!("makeletterclosing"))
+}
+
+/// Enclosures listed below the signature
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Enclosure {
+    pub label: Option<TexStr<'static>>,
+    pub text: TexStr<'static>,
+}
+
+/// A cover letter, sharing the personal information of a `Resume`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Letter {
+    pub recipient: TexStr<'static>,
+    /// Address lines of the recipient
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub address: Vec<TexStr<'static>>,
+    pub date: Option<TexStr<'static>>,
+    pub opening: TexStr<'static>,
+    pub closing: TexStr<'static>,
+    pub enclosure: Option<Enclosure>,
+    /// Paragraphs of the letter
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub body: Vec<TexStr<'static>>,
+}
+
+impl Letter {
+    /// Render the letter, from the recipient to `\makeletterclosing`
+    pub fn to_elements(&self) -> Vec<Element> {
+        let address = self
+            .address
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join(r"\\");
+
+        let mut elements = vec![recipient(&self.recipient, TexStr::raw(address))];
+
+        if let Some(d) = &self.date {
+            elements.push(date(d));
+        }
+
+        elements.push(opening(&self.opening));
+        elements.push(closing(&self.closing));
+
+        if let Some(e) = &self.enclosure {
+            elements.push(enclosure(e.label.as_ref().map(TexStr::from), &e.text));
+        }
+
+        elements.push(makelettertitle());
+
+        let body = self
+            .body
+            .iter()
+            .map(|paragraph| paragraph.to_string())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        elements.push(Element::UserDefined(body));
+
+        elements.push(makeletterclosing());
+
+        elements
+    }
+}
@@ -5,9 +5,15 @@
 //! Just import `CVPreamble` and `CVSection`, then you can generate resume easily.
 //! You can use pdflatex, lualatex or whatever you like to generate the pdf file.
 //!
+//! Plain text arguments are escaped automatically, wrap LaTeX code with `TexStr::raw()`
+//! to pass it through unchanged.
+//!
 //! ```
-//! use latex::{Document, Element, Section};
-//! use moderncv::{document_class, CVPreamble, CVSection, Color, PhoneType, SocialType, Style};
+//! use latex::{Document, Section};
+//! use moderncv::{
+//!     document_class, CVDocument, CVPreamble, CVSection, Color, PhoneType, SocialType, Style,
+//!     TexStr,
+//! };
 //!
 //! fn main() {
 //!     // Create LaTeX document
@@ -20,13 +26,13 @@
 //!         .firstname("Firstname")
 //!         .lastname("Lastname")
 //!         .photo("myphoto.jpg", Some("64pt"), None)
-//!         .address("Street", Some("Your city"), Some("Your country"))
+//!         .address("Street", Some("Your city".into()), Some("Your country".into()))
 //!         .phone("+1234567890", Some(PhoneType::Mobile))
 //!         .email("address@domain.com")
 //!         .social("github_account", SocialType::Github, None)
 //!         .homepage("https://xxx.yyy.zzz/page");
 //!
-//!     doc.push(Element::TitlePage);
+//!     doc.makecvtitle();
 //!
 //!     // Education details
 //!     let mut education = Section::new("Education");
@@ -36,24 +42,24 @@
 //!             "Degree",
 //!             "Institution",
 //!             "City",
-//!             Some("Grade"),
-//!             Some("Description"),
+//!             Some("Grade".into()),
+//!             Some("Description".into()),
 //!         )
 //!         .cventry(
 //!             "year--year",
 //!             "Degree",
 //!             "Institution",
 //!             "City",
-//!             Some("Grade"),
-//!             Some("Description"),
+//!             Some("Grade".into()),
+//!             Some("Description".into()),
 //!         );
 //!     doc.push(education);
 //!
 //!     let mut thesis = Section::new("Master thesis");
 //!     thesis
-//!         .cvline("title", r"\emph{Title}")
+//!         .cvline("title", TexStr::raw(r"\emph{Title}"))
 //!         .cvline("supervisor", "Supervisors")
-//!         .cvline("descriptin", r"\small Short thesis abstract");
+//!         .cvline("descriptin", TexStr::raw(r"\small Short thesis abstract"));
 //!     doc.push(thesis);
 //!
 //!     // Experience
@@ -65,7 +71,7 @@
 //!             "Employer 1",
 //!             "City 1",
 //!             None,
-//!             Some("Description 1"),
+//!             Some("Description 1".into()),
 //!         )
 //!         .cventry(
 //!             "year--year",
@@ -73,7 +79,7 @@
 //!             "Employer 2",
 //!             "City 2",
 //!             None,
-//!             Some("Description"),
+//!             Some("Description".into()),
 //!         );
 //!     doc.push(experience);
 //!
@@ -94,9 +100,9 @@
 //!     // Interests
 //!     let mut interests = Section::new("Interests");
 //!     interests
-//!         .cvline("Hobby 1", r"\small Description")
-//!         .cvline("Hobby 2", r"\small Description")
-//!         .cvline("Hobby 3", r"\small Description");
+//!         .cvline("Hobby 1", TexStr::raw(r"\small Description"))
+//!         .cvline("Hobby 2", TexStr::raw(r"\small Description"))
+//!         .cvline("Hobby 3", TexStr::raw(r"\small Description"));
 //!     doc.push(interests);
 //!
 //!     // Extra
@@ -113,15 +119,45 @@
 //! }
 //! ```
 
-use latex::{DocumentClass, Element};
+use latex::DocumentClass;
 
+pub mod chronology;
+pub mod class;
+#[cfg(feature = "compile")]
+pub mod compile;
+pub mod date;
+pub mod description;
+pub mod document;
+pub mod fonts;
+#[cfg(feature = "jsonresume")]
+pub mod jsonresume;
+pub mod letter;
+pub mod link;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod metadata;
 pub mod preamble;
+pub mod presets;
+pub mod render;
+pub mod resume;
 pub mod section;
+#[cfg(feature = "source")]
+pub mod source;
+pub mod tex;
+pub mod unicode;
 
+pub use class::ClassOptions;
+pub use document::{closesection, emptysection, CVDocument, TitlePlacement};
+pub use letter::CVLetter;
 pub use preamble::{CVPreamble, Color, PhoneType, SocialType, Style};
+pub use render::RenderOptions;
+pub use resume::Resume;
 pub use section::CVSection;
+pub use tex::TexStr;
 
 /// TeXify strings
+///
+/// Arguments are rendered with `Display`, so `TexStr` arguments are escaped as needed.
 #[macro_export]
 macro_rules! texify {
     ($name:expr) => {{
@@ -140,34 +176,67 @@ macro_rules! texify {
             s.push_str(&format!(r"{{{}}}", $extra));
         )+
 
-            s
+        s
     }};
 }
 
-/// DocumentClass for moderncv
-pub fn document_class() -> DocumentClass {
-    DocumentClass::Other("moderncv".to_string())
+/// Moderncv releases with different command sets, picked by `RenderOptions`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum ModernCvVersion {
+    /// moderncv 1.x: `\moderncvtheme`, `\firstname`/`\familyname`, `\cvline`, `\cvlanguage`
+    #[default]
+    V1,
+    /// moderncv 2.x: `\moderncvstyle`/`\moderncvcolor`, `\name`, `\cvitem`, `\cvitemwithcomment`
+    V2,
 }
 
-/// Close section(`\closesection{}`)
-pub fn closesection() -> Element {
-    let empty = "";
-    let s = texify!("closesection", empty);
+/// LaTeX engines
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Engine {
+    #[default]
+    Pdflatex,
+    Xelatex,
+    Lualatex,
+    /// `latexmk -pdf`, reruns pdflatex as needed
+    Latexmk,
+}
+
+impl Engine {
+    /// Name of the executable
+    pub fn program(self) -> &'static str {
+        match self {
+            Self::Pdflatex => "pdflatex",
+            Self::Xelatex => "xelatex",
+            Self::Lualatex => "lualatex",
+            Self::Latexmk => "latexmk",
+        }
+    }
 
-    Element::UserDefined(s)
+    /// Whether the engine reads UTF-8 natively and loads system fonts with fontspec
+    pub fn is_unicode(self) -> bool {
+        matches!(self, Self::Xelatex | Self::Lualatex)
+    }
 }
 
-/// Empty section(`\emptysection{}`)
-pub fn emptysection() -> Element {
-    let empty = "";
-    let s = texify!("emptysection", empty);
+impl std::fmt::Display for Engine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.program())
+    }
+}
 
-    Element::UserDefined(s)
+/// DocumentClass for moderncv
+///
+/// It has no options, print with `ClassOptions::print()` to set them.
+pub fn document_class() -> DocumentClass {
+    DocumentClass::Other("moderncv".to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use latex::Element;
 
     #[test]
     fn test_document_class() {
@@ -0,0 +1,181 @@
+//! Hyperlinks usable as any command argument
+//!
+//! A [`Link`] converts into a raw [`TexStr`], so it can be passed wherever a
+//! text argument is expected:
+//!
+//! ```
+//! use moderncv::link::Link;
+//! use moderncv::section::cventry;
+//!
+//! let project = Link::href("https://github.com/jdoe/my_project#readme", "my_project");
+//! let entry = cventry("2021", project, "Open source", "", None, None);
+//! ```
+//!
+//! Converted links use `\href`, which every moderncv release knows;
+//! [`Link::to_tex`] emits `\httplink` and `\emaillink` for moderncv 2.x.
+//!
+//! URLs are escaped for use inside arguments: `#` and `%` get a backslash,
+//! spaces, `~` and non-ASCII characters are percent-encoded. Displayed URLs
+//! are escaped like plain text, so `_` does not break compilation.
+
+use crate::tex::TexStr;
+use crate::ModernCvVersion;
+use std::borrow::Cow;
+
+/// A hyperlink
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Link {
+    /// `\href{url}{text}`
+    Href { url: String, text: TexStr<'static> },
+    /// `\httplink[text]{url}` or `\httpslink`, the URL is displayed by default
+    Http {
+        url: String,
+        text: Option<TexStr<'static>>,
+    },
+    /// `\emaillink[text]{address}`, the address is displayed by default
+    Email {
+        address: String,
+        text: Option<TexStr<'static>>,
+    },
+}
+
+impl Link {
+    /// Link `text` to `url`
+    pub fn href(url: &str, text: impl Into<TexStr<'static>>) -> Self {
+        Self::Href {
+            url: url.to_string(),
+            text: text.into(),
+        }
+    }
+
+    /// Link to a web page, `http://` is assumed without scheme
+    pub fn http(url: &str) -> Self {
+        Self::Http {
+            url: url.to_string(),
+            text: None,
+        }
+    }
+
+    /// Link to an email address
+    pub fn email(address: &str) -> Self {
+        Self::Email {
+            address: address.to_string(),
+            text: None,
+        }
+    }
+
+    /// Display `text` instead of the URL or address
+    pub fn text(self, text: impl Into<TexStr<'static>>) -> Self {
+        let text = text.into();
+        match self {
+            Self::Href { url, .. } => Self::Href { url, text },
+            Self::Http { url, .. } => Self::Http {
+                url,
+                text: Some(text),
+            },
+            Self::Email { address, .. } => Self::Email {
+                address,
+                text: Some(text),
+            },
+        }
+    }
+}
+
+impl Link {
+    /// Render the link with the commands of `version`
+    ///
+    /// moderncv 1.x has no optional text on its link commands, `\href` is used instead.
+    pub fn to_tex(&self, version: ModernCvVersion) -> TexStr<'static> {
+        let s = match self {
+            Self::Href { url, text } => format!(r"\href{{{}}}{{{}}}", escape_url(url), text),
+            Self::Http { url, text } => {
+                let text = text.clone().unwrap_or_else(|| TexStr::text(url.as_str()));
+                let (command, rest) = if let Some(rest) = url.strip_prefix("https://") {
+                    ("httpslink", rest)
+                } else {
+                    ("httplink", url.strip_prefix("http://").unwrap_or(url))
+                };
+
+                match version {
+                    ModernCvVersion::V1 => {
+                        let scheme = if command == "httpslink" {
+                            "https"
+                        } else {
+                            "http"
+                        };
+                        format!(r"\href{{{}://{}}}{{{}}}", scheme, escape_url(rest), text)
+                    }
+                    ModernCvVersion::V2 => {
+                        format!(r"\{}[{}]{{{}}}", command, text, escape_url(rest))
+                    }
+                }
+            }
+            Self::Email { address, text } => {
+                let text = text
+                    .clone()
+                    .unwrap_or_else(|| TexStr::text(address.as_str()));
+
+                match version {
+                    ModernCvVersion::V1 => {
+                        format!(r"\href{{mailto:{}}}{{{}}}", escape_url(address), text)
+                    }
+                    ModernCvVersion::V2 => {
+                        format!(r"\emaillink[{}]{{{}}}", text, escape_url(address))
+                    }
+                }
+            }
+        };
+
+        TexStr::raw(s)
+    }
+}
+
+/// Rendered for moderncv 1.x, see `to_tex`
+impl std::fmt::Display for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_tex(ModernCvVersion::V1))
+    }
+}
+
+impl From<Link> for TexStr<'static> {
+    fn from(link: Link) -> Self {
+        link.to_tex(ModernCvVersion::V1)
+    }
+}
+
+impl From<&Link> for TexStr<'static> {
+    fn from(link: &Link) -> Self {
+        link.to_tex(ModernCvVersion::V1)
+    }
+}
+
+/// Escape an URL for `\href` used inside the argument of another command
+pub fn escape_url(url: &str) -> Cow<'_, str> {
+    if url.is_ascii() && !url.contains(['#', '%', ' ', '~', '\\', '{', '}']) {
+        return Cow::Borrowed(url);
+    }
+
+    let mut escaped = String::with_capacity(url.len() + 8);
+    for c in url.chars() {
+        match c {
+            '#' | '%' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' ' => escaped.push_str(r"\%20"),
+            '~' => escaped.push_str(r"\%7E"),
+            '\\' => escaped.push_str(r"\%5C"),
+            '{' => escaped.push_str(r"\%7B"),
+            '}' => escaped.push_str(r"\%7D"),
+            c if c.is_ascii() => escaped.push(c),
+            c => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    escaped.push_str(&format!(r"\%{:02X}", byte));
+                }
+            }
+        }
+    }
+
+    Cow::Owned(escaped)
+}
@@ -0,0 +1,207 @@
+use clap::{Parser, Subcommand};
+use moderncv::compile::{Compiler, Engine, Severity};
+use moderncv::date::{DateFormat, Language};
+use moderncv::{ModernCvVersion, RenderOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Exit codes, following sysexits(3) where one applies
+mod exit {
+    /// The LaTeX engine failed to produce the PDF
+    pub const ENGINE_FAILED: u8 = 1;
+    /// The resume file is not valid
+    pub const DATAERR: u8 = 65;
+    /// The resume file cannot be read
+    pub const NOINPUT: u8 = 66;
+    /// The LaTeX engine is not installed
+    pub const UNAVAILABLE: u8 = 69;
+    /// The output file cannot be written
+    pub const CANTCREAT: u8 = 73;
+}
+
+/// Generate moderncv LaTeX resumes from YAML, TOML or JSON files
+///
+/// Exit status: 0 on success, 1 if the LaTeX engine failed, 2 on usage errors,
+/// 65 for invalid resume files, 66 if the input cannot be read,
+/// 69 if the LaTeX engine is not installed and 73 if the output cannot be written.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Render a resume file to LaTeX and optionally to PDF
+    Build {
+        /// Resume file (.yaml, .yml, .toml or .json)
+        input: PathBuf,
+
+        /// Output LaTeX file, `-` for standard output
+        #[arg(short, long, default_value = "resume.tex")]
+        output: PathBuf,
+
+        /// Compile the PDF with a locally installed LaTeX engine
+        #[arg(long)]
+        pdf: bool,
+
+        /// LaTeX engine used with `--pdf`
+        #[arg(long, value_enum, default_value_t = Engine::Latexmk)]
+        engine: Engine,
+
+        /// Targeted moderncv release
+        #[arg(long, value_enum, default_value_t = ModernCvVersion::V1)]
+        moderncv: ModernCvVersion,
+
+        /// Read free text of entries and the quote as inline Markdown
+        #[arg(long)]
+        markdown: bool,
+
+        /// Format of the dates of entries
+        #[arg(long, value_enum, default_value_t = DateFormat::Iso)]
+        date_format: DateFormat,
+
+        /// Language of month names and of `present`
+        #[arg(long, value_enum, default_value_t = Language::English)]
+        language: Language,
+    },
+    /// Report employment gaps and overlapping positions of each section
+    Lint {
+        /// Resume file (.yaml, .yml, .toml or .json)
+        input: PathBuf,
+
+        /// Longest gap in months which is not reported
+        #[arg(long, default_value_t = 3)]
+        max_gap: u32,
+    },
+    /// Print the JSON Schema of resume files
+    Schema,
+}
+
+fn load(input: &Path) -> Result<moderncv::Resume, (u8, String)> {
+    moderncv::source::from_path(input).map_err(|e| {
+        let code = match e {
+            moderncv::source::Error::Io(_) => exit::NOINPUT,
+            _ => exit::DATAERR,
+        };
+        (code, format!("{}: {}", input.display(), e))
+    })
+}
+
+fn lint(input: &Path, max_gap: u32) -> Result<(), (u8, String)> {
+    let resume = load(input)?;
+
+    for section in &resume.sections {
+        for issue in section.lint(max_gap) {
+            println!("{}: {}: {}", input.display(), section.title, issue);
+        }
+    }
+
+    Ok(())
+}
+
+fn build(
+    input: &Path,
+    output: &Path,
+    options: &RenderOptions,
+    pdf: Option<Engine>,
+) -> Result<(), (u8, String)> {
+    let resume = load(input)?;
+
+    let rendered = resume.render(options);
+    for unrepresentable in &rendered.unrepresentable {
+        eprintln!("moderncv: warning: {}", unrepresentable);
+    }
+
+    let rendered = latex::print(&rendered.document)
+        .map_err(|e| (exit::DATAERR, format!("{}: {}", input.display(), e)))?;
+
+    let pdf_path = if output == Path::new("-") {
+        print!("{}", rendered);
+        Path::new(input.file_stem().unwrap_or_default()).with_extension("pdf")
+    } else {
+        let cantcreat =
+            |e: std::io::Error| (exit::CANTCREAT, format!("{}: {}", output.display(), e));
+        std::fs::File::create(output)
+            .and_then(|mut f| f.write_all(rendered.as_bytes()))
+            .map_err(cantcreat)?;
+        output.with_extension("pdf")
+    };
+
+    let engine = match pdf {
+        Some(engine) => engine,
+        None => return Ok(()),
+    };
+
+    let base_dir = input.parent().unwrap_or_else(|| Path::new(""));
+    let compiled = Compiler::new(engine)
+        .compile_resume(&resume, options, &rendered, base_dir)
+        .map_err(|e| match e {
+            moderncv::compile::Error::EngineNotFound(_) => (exit::UNAVAILABLE, e.to_string()),
+            moderncv::compile::Error::InvalidAsset(_)
+            | moderncv::compile::Error::MissingAsset(_) => (exit::DATAERR, e.to_string()),
+            moderncv::compile::Error::Io(_) => (exit::CANTCREAT, e.to_string()),
+            moderncv::compile::Error::Failed {
+                ref diagnostics, ..
+            } => {
+                for diagnostic in diagnostics.iter().filter(|d| d.severity == Severity::Error) {
+                    eprintln!("moderncv: {}", diagnostic);
+                }
+                (exit::ENGINE_FAILED, format!("{} failed", engine))
+            }
+        })?;
+
+    for diagnostic in &compiled.diagnostics {
+        eprintln!("moderncv: {}", diagnostic);
+    }
+
+    std::fs::write(&pdf_path, &compiled.pdf)
+        .map_err(|e| (exit::CANTCREAT, format!("{}: {}", pdf_path.display(), e)))?;
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Commands::Build {
+            input,
+            output,
+            pdf,
+            engine,
+            moderncv,
+            markdown,
+            date_format,
+            language,
+        } => {
+            let options = *RenderOptions::new()
+                .version(moderncv)
+                .engine(engine)
+                .markdown(markdown)
+                .date_format(date_format)
+                .language(language);
+            build(
+                &input,
+                &output,
+                &options,
+                if pdf { Some(engine) } else { None },
+            )
+        }
+        Commands::Lint { input, max_gap } => lint(&input, max_gap),
+        Commands::Schema => {
+            println!("{}", moderncv::source::json_schema());
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err((code, message)) => {
+            eprintln!("moderncv: {}", message);
+            ExitCode::from(code)
+        }
+    }
+}
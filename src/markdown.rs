@@ -0,0 +1,73 @@
+//! Inline Markdown in free text
+//!
+//! `**bold**`, `_italic_`, `` `code` `` and `[links](https://...)` become
+//! `\textbf`, `\emph`, `\texttt` and `\href`. Anything else, like headings or
+//! lists, is escaped. Convert arguments of the `section` helpers with
+//! [`TexStr::markdown`](crate::TexStr::markdown), or read the plain text of a
+//! resume's entries and quote as Markdown with `RenderOptions::markdown()`:
+//!
+//! ```
+//! use moderncv::section::cvitem;
+//! use moderncv::{RenderOptions, Resume, TexStr};
+//!
+//! let item = cvitem("Skills", TexStr::markdown("**Rust** and _C_"));
+//!
+//! let doc = Resume::new().render(RenderOptions::new().markdown(true));
+//! ```
+
+use crate::link::escape_url;
+use crate::tex::escape;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Convert inline Markdown to LaTeX, paragraphs are separated by `\newline{}`
+pub fn to_latex(s: &str) -> String {
+    let mut blocks = Vec::new();
+    let mut out = String::new();
+    let mut in_paragraph = false;
+
+    let mut events = Parser::new_ext(s, Options::empty()).into_offset_iter();
+    while let Some((event, range)) = events.next() {
+        match event {
+            Event::Start(Tag::Paragraph) => in_paragraph = true,
+            Event::End(TagEnd::Paragraph) => {
+                in_paragraph = false;
+                blocks.push(std::mem::take(&mut out));
+            }
+            Event::Start(Tag::Emphasis) => out.push_str(r"\emph{"),
+            Event::Start(Tag::Strong) => out.push_str(r"\textbf{"),
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                out.push_str(&format!(r"\href{{{}}}{{", escape_url(&dest_url)));
+            }
+            Event::End(TagEnd::Emphasis | TagEnd::Strong | TagEnd::Link) => out.push('}'),
+            Event::Text(text) => out.push_str(&escape(&text)),
+            Event::Code(code) => out.push_str(&format!(r"\texttt{{{}}}", escape(&code))),
+            Event::SoftBreak => out.push(' '),
+            Event::HardBreak => out.push_str(r"\newline{}"),
+            other => {
+                // Skip the content of unsupported elements, their source is escaped instead
+                if let Event::Start(_) = other {
+                    let mut depth = 1;
+                    for (event, _) in events.by_ref() {
+                        match event {
+                            Event::Start(_) => depth += 1,
+                            Event::End(_) => depth -= 1,
+                            _ => {}
+                        }
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+
+                let source = escape(s[range].trim_end()).into_owned();
+                if in_paragraph {
+                    out.push_str(&source);
+                } else {
+                    blocks.push(source);
+                }
+            }
+        }
+    }
+
+    blocks.join(r"\newline{}")
+}
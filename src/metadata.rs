@@ -0,0 +1,123 @@
+//! PDF metadata set with hyperref
+//!
+//! moderncv fills the PDF information from the name at the end of the
+//! preamble, so the values are set again with `\AtBeginDocument` to win.
+//! A [`Resume`](crate::Resume) defaults the author to its personal name.
+//!
+//! ```
+//! use latex::Document;
+//! use moderncv::metadata::Metadata;
+//! use moderncv::{document_class, CVPreamble};
+//!
+//! let mut doc = Document::new(document_class());
+//! doc.preamble.title("Resume");
+//! doc.preamble.name("John", "Doe").metadata(
+//!     Metadata::new()
+//!         .author("John Doe")
+//!         .subject("Software engineer")
+//!         .keyword("Rust"),
+//! );
+//! ```
+
+use crate::tex::TexStr;
+use latex::{Preamble, PreambleElement};
+
+/// PDF title, author, subject and keywords
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Metadata {
+    /// Defaults to the title of the preamble
+    pub title: Option<TexStr<'static>>,
+    /// Defaults to the personal name of a resume
+    pub author: Option<TexStr<'static>>,
+    pub subject: Option<TexStr<'static>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keywords: Vec<TexStr<'static>>,
+}
+
+impl Metadata {
+    /// Create empty metadata
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the title (`pdftitle`)
+    pub fn title(&mut self, title: impl Into<TexStr<'static>>) -> &mut Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    /// Set the author (`pdfauthor`)
+    pub fn author(&mut self, author: impl Into<TexStr<'static>>) -> &mut Self {
+        self.author = Some(author.into());
+
+        self
+    }
+
+    /// Set the subject (`pdfsubject`)
+    pub fn subject(&mut self, subject: impl Into<TexStr<'static>>) -> &mut Self {
+        self.subject = Some(subject.into());
+
+        self
+    }
+
+    /// Add a keyword (`pdfkeywords`)
+    pub fn keyword(&mut self, keyword: impl Into<TexStr<'static>>) -> &mut Self {
+        self.keywords.push(keyword.into());
+
+        self
+    }
+
+    /// Fill the missing title from `Preamble::title`
+    pub fn with_defaults(&self, preamble: &Preamble) -> Self {
+        let mut metadata = self.clone();
+
+        if metadata.title.is_none() {
+            metadata.title = preamble.title.clone().map(TexStr::raw);
+        }
+
+        metadata
+    }
+
+    /// The `\hypersetup` of the set values, `None` if there is none
+    pub fn to_element(&self) -> Option<PreambleElement> {
+        let keywords = self
+            .keywords
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let keywords = if keywords.is_empty() {
+            None
+        } else {
+            Some(TexStr::raw(keywords))
+        };
+
+        let options = [
+            ("pdftitle", &self.title),
+            ("pdfauthor", &self.author),
+            ("pdfsubject", &self.subject),
+            ("pdfkeywords", &keywords),
+        ]
+        .iter()
+        .filter_map(|(key, value)| value.as_ref().map(|value| format!("{}={{{}}}", key, value)))
+        .collect::<Vec<_>>();
+
+        if options.is_empty() {
+            return None;
+        }
+
+        let s = format!(
+            "\\AtBeginDocument{{\\hypersetup{{\n  {}\n}}}}",
+            options.join(",\n  ")
+        );
+
+        Some(PreambleElement::UserDefined(s))
+    }
+}
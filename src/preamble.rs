@@ -1,7 +1,19 @@
 use super::texify;
+use crate::fonts::{FontError, Fonts};
+use crate::link::escape_url;
+use crate::metadata::Metadata;
+use crate::presets::Presets;
+use crate::tex::TexStr;
 use latex::{Preamble, PreambleElement};
 
 /// Social media types
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum SocialType {
     LinkedIn,
     XING,
@@ -39,6 +51,13 @@ impl std::fmt::Display for SocialType {
 }
 
 /// Phone types
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum PhoneType {
     Fixed,
     Mobile,
@@ -56,6 +75,13 @@ impl std::fmt::Display for PhoneType {
 }
 
 /// Moderncv colors
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Color {
     Black,
     Blue,
@@ -83,6 +109,13 @@ impl std::fmt::Display for Color {
 }
 
 /// Moderncv styles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Style {
     Banking,
     Casual,
@@ -105,29 +138,224 @@ impl std::fmt::Display for Style {
     }
 }
 
+/// Theme colors which can be overridden with `definecolor`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorSlot {
+    /// Text color
+    Color0,
+    /// Main color of the theme, used for the name, rules and section titles
+    Color1,
+    /// Secondary color of the theme
+    Color2,
+}
+
+impl std::fmt::Display for ColorSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Color0 => write!(f, "color0"),
+            Self::Color1 => write!(f, "color1"),
+            Self::Color2 => write!(f, "color2"),
+        }
+    }
+}
+
+/// Errors of invalid custom colors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColorError {
+    /// Not six hexadecimal digits
+    InvalidHtml(String),
+    /// Not three components between 0 and 1
+    InvalidRgb(String),
+}
+
+impl std::fmt::Display for ColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHtml(s) => write!(f, "invalid HTML color `{}`, expected `#RRGGBB`", s),
+            Self::InvalidRgb(s) => write!(
+                f,
+                "invalid rgb color `{}`, expected three components between 0 and 1",
+                s
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+/// A validated color in the `HTML` or `rgb` model of xcolor
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomColor {
+    model: &'static str,
+    value: String,
+}
+
+impl CustomColor {
+    /// Make a color from six hexadecimal digits, with or without a leading `#`
+    pub fn html(hex: &str) -> Result<Self, ColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorError::InvalidHtml(hex.to_string()));
+        }
+
+        Ok(Self {
+            model: "HTML",
+            value: digits.to_uppercase(),
+        })
+    }
+
+    /// Make a color from red, green and blue components between 0 and 1
+    pub fn rgb(red: f32, green: f32, blue: f32) -> Result<Self, ColorError> {
+        let components = [red, green, blue];
+        if !components.iter().all(|c| (0.0..=1.0).contains(c)) {
+            return Err(ColorError::InvalidRgb(format!(
+                "{}, {}, {}",
+                red, green, blue
+            )));
+        }
+
+        Ok(Self {
+            model: "rgb",
+            value: format!("{},{},{}", red, green, blue),
+        })
+    }
+
+    /// The xcolor model, `HTML` or `rgb`
+    pub fn model(&self) -> &str {
+        self.model
+    }
+
+    /// The value in the xcolor syntax of the model
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// Parse `#RRGGBB` or `rgb(r, g, b)`
+impl std::str::FromStr for CustomColor {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = match s.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')) {
+            Some(components) => components,
+            None => return Self::html(s),
+        };
+
+        let invalid = || ColorError::InvalidRgb(s.to_string());
+        let components = components
+            .split(',')
+            .map(|c| c.trim().parse::<f32>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match components[..] {
+            [red, green, blue] => Self::rgb(red, green, blue).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Formatted as `#RRGGBB` or `rgb(r,g,b)`, the input of `from_str`
+impl std::fmt::Display for CustomColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.model {
+            "HTML" => write!(f, "#{}", self.value),
+            _ => write!(f, "rgb({})", self.value),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CustomColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CustomColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A string, `#RRGGBB` or `rgb(r, g, b)`
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for CustomColor {
+    fn schema_name() -> String {
+        "CustomColor".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, Metadata, SchemaObject};
+
+        let schema = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "`#RRGGBB` or `rgb(r, g, b)` with components between 0 and 1".to_string(),
+                ),
+                ..Metadata::default()
+            })),
+            ..SchemaObject::default()
+        };
+
+        schema.into()
+    }
+}
+
 /// A trait which implement useful functions for moderncv preamble
 pub trait CVPreamble {
-    fn name(&mut self, firstname: &str, lastname: &str) -> &mut Self;
-    fn firstname(&mut self, name: &str) -> &mut Self;
-    fn lastname(&mut self, name: &str) -> &mut Self;
-    fn givenname(&mut self, name: &str) -> &mut Self;
-    fn familyname(&mut self, name: &str) -> &mut Self;
-    fn address(&mut self, street: &str, city: Option<&str>, country: Option<&str>) -> &mut Self;
-    fn mobile(&mut self, number: &str) -> &mut Self;
-    fn phone(&mut self, number: &str, phone_type: Option<PhoneType>) -> &mut Self;
-    fn fax(&mut self, number: &str) -> &mut Self;
-    fn email(&mut self, address: &str) -> &mut Self;
-    fn social(&mut self, account: &str, social_type: SocialType, url: Option<&str>) -> &mut Self;
-    fn homepage(&mut self, url: &str) -> &mut Self;
+    fn name<'a>(
+        &mut self,
+        firstname: impl Into<TexStr<'a>>,
+        lastname: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn firstname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self;
+    fn lastname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self;
+    fn givenname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self;
+    fn familyname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self;
+    fn address<'a>(
+        &mut self,
+        street: impl Into<TexStr<'a>>,
+        city: Option<TexStr<'a>>,
+        country: Option<TexStr<'a>>,
+    ) -> &mut Self;
+    fn mobile<'a>(&mut self, number: impl Into<TexStr<'a>>) -> &mut Self;
+    fn phone<'a>(
+        &mut self,
+        number: impl Into<TexStr<'a>>,
+        phone_type: Option<PhoneType>,
+    ) -> &mut Self;
+    fn fax<'a>(&mut self, number: impl Into<TexStr<'a>>) -> &mut Self;
+    fn email<'a>(&mut self, address: impl Into<TexStr<'a>>) -> &mut Self;
+    fn social<'a>(
+        &mut self,
+        account: impl Into<TexStr<'a>>,
+        social_type: SocialType,
+        url: Option<TexStr<'a>>,
+    ) -> &mut Self;
+    fn homepage<'a>(&mut self, url: impl Into<TexStr<'a>>) -> &mut Self;
     fn cvtheme(&mut self, style: Style, color: Option<Color>) -> &mut Self;
-    fn extrainfo(&mut self, info: &str) -> &mut Self;
+    fn moderncvstyle(&mut self, style: Style) -> &mut Self;
+    fn moderncvcolor(&mut self, color: Color) -> &mut Self;
+    fn definecolor(&mut self, slot: ColorSlot, color: &CustomColor) -> &mut Self;
+    fn extrainfo<'a>(&mut self, info: impl Into<TexStr<'a>>) -> &mut Self;
     fn photo(&mut self, photo: &str, width: Option<&str>, frame: Option<&str>) -> &mut Self;
-    fn quote(&mut self, msg: &str) -> &mut Self;
+    fn quote<'a>(&mut self, msg: impl Into<TexStr<'a>>) -> &mut Self;
+    fn presets(&mut self, presets: &Presets) -> &mut Self;
+    fn fonts(&mut self, fonts: &Fonts) -> Result<&mut Self, FontError>;
+    fn metadata(&mut self, metadata: &Metadata) -> &mut Self;
 }
 
 impl CVPreamble for Preamble {
     /// Set name
-    fn name(&mut self, firstname: &str, lastname: &str) -> &mut Self {
+    fn name<'a>(
+        &mut self,
+        firstname: impl Into<TexStr<'a>>,
+        lastname: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::name(firstname, lastname);
         self.push(elem);
 
@@ -135,7 +363,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set first name
-    fn firstname(&mut self, name: &str) -> &mut Self {
+    fn firstname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::firstname(name);
         self.push(elem);
 
@@ -143,7 +371,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set last name
-    fn lastname(&mut self, name: &str) -> &mut Self {
+    fn lastname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::lastname(name);
         self.push(elem);
 
@@ -151,7 +379,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set given name (First name)
-    fn givenname(&mut self, name: &str) -> &mut Self {
+    fn givenname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::givenname(name);
         self.push(elem);
 
@@ -159,7 +387,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set family name (Last name)
-    fn familyname(&mut self, name: &str) -> &mut Self {
+    fn familyname<'a>(&mut self, name: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::familyname(name);
         self.push(elem);
 
@@ -167,7 +395,12 @@ impl CVPreamble for Preamble {
     }
 
     /// Set address
-    fn address(&mut self, street: &str, city: Option<&str>, country: Option<&str>) -> &mut Self {
+    fn address<'a>(
+        &mut self,
+        street: impl Into<TexStr<'a>>,
+        city: Option<TexStr<'a>>,
+        country: Option<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::address(street, city, country);
         self.push(elem);
 
@@ -175,7 +408,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set mobile number
-    fn mobile(&mut self, number: &str) -> &mut Self {
+    fn mobile<'a>(&mut self, number: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::mobile(number);
         self.push(elem);
 
@@ -183,7 +416,11 @@ impl CVPreamble for Preamble {
     }
 
     /// Set phone number
-    fn phone(&mut self, number: &str, phone_type: Option<PhoneType>) -> &mut Self {
+    fn phone<'a>(
+        &mut self,
+        number: impl Into<TexStr<'a>>,
+        phone_type: Option<PhoneType>,
+    ) -> &mut Self {
         let elem = self::phone(number, phone_type);
         self.push(elem);
 
@@ -191,7 +428,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set fax number
-    fn fax(&mut self, number: &str) -> &mut Self {
+    fn fax<'a>(&mut self, number: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::fax(number);
         self.push(elem);
 
@@ -199,7 +436,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set email address
-    fn email(&mut self, address: &str) -> &mut Self {
+    fn email<'a>(&mut self, address: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::email(address);
         self.push(elem);
 
@@ -207,7 +444,12 @@ impl CVPreamble for Preamble {
     }
 
     /// Set social link
-    fn social(&mut self, account: &str, social_type: SocialType, url: Option<&str>) -> &mut Self {
+    fn social<'a>(
+        &mut self,
+        account: impl Into<TexStr<'a>>,
+        social_type: SocialType,
+        url: Option<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::social(account, social_type, url);
         self.push(elem);
 
@@ -215,7 +457,7 @@ impl CVPreamble for Preamble {
     }
 
     /// Set home page
-    fn homepage(&mut self, url: &str) -> &mut Self {
+    fn homepage<'a>(&mut self, url: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::homepage(url);
         self.push(elem);
 
@@ -230,8 +472,32 @@ impl CVPreamble for Preamble {
         self
     }
 
+    /// Set moderncv style (moderncv 2.x)
+    fn moderncvstyle(&mut self, style: Style) -> &mut Self {
+        let elem = self::moderncvstyle(style);
+        self.push(elem);
+
+        self
+    }
+
+    /// Set moderncv color (moderncv 2.x)
+    fn moderncvcolor(&mut self, color: Color) -> &mut Self {
+        let elem = self::moderncvcolor(color);
+        self.push(elem);
+
+        self
+    }
+
+    /// Override a theme color, after `cvtheme`
+    fn definecolor(&mut self, slot: ColorSlot, color: &CustomColor) -> &mut Self {
+        let elem = self::definecolor(slot, color);
+        self.push(elem);
+
+        self
+    }
+
     /// Set extra information
-    fn extrainfo(&mut self, info: &str) -> &mut Self {
+    fn extrainfo<'a>(&mut self, info: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::extrainfo(info);
         self.push(elem);
 
@@ -247,103 +513,141 @@ impl CVPreamble for Preamble {
     }
 
     /// Set quote string
-    fn quote(&mut self, msg: &str) -> &mut Self {
+    fn quote<'a>(&mut self, msg: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::quote(msg);
         self.push(elem);
 
         self
     }
+
+    /// Set up the standard packages and lengths
+    fn presets(&mut self, presets: &Presets) -> &mut Self {
+        self.extend(presets.to_elements());
+
+        self
+    }
+
+    /// Load the fonts, fails for fontspec fonts with pdflatex
+    fn fonts(&mut self, fonts: &Fonts) -> Result<&mut Self, FontError> {
+        self.extend(fonts.to_elements()?);
+
+        Ok(self)
+    }
+
+    /// Set the PDF metadata, the title defaults to the one already set
+    fn metadata(&mut self, metadata: &Metadata) -> &mut Self {
+        if let Some(elem) = metadata.with_defaults(self).to_element() {
+            self.push(elem);
+        }
+
+        self
+    }
 }
 
 /// Set name
-pub fn name(firstname: &str, lastname: &str) -> PreambleElement {
+pub fn name<'a>(
+    firstname: impl Into<TexStr<'a>>,
+    lastname: impl Into<TexStr<'a>>,
+) -> PreambleElement {
+    let (firstname, lastname) = (firstname.into(), lastname.into());
     let s = texify!("name", firstname, lastname);
     PreambleElement::UserDefined(s)
 }
 
 /// Set first name
-pub fn firstname(name: &str) -> PreambleElement {
+pub fn firstname<'a>(name: impl Into<TexStr<'a>>) -> PreambleElement {
+    let name = name.into();
     let s = texify!("firstname", name);
     PreambleElement::UserDefined(s)
 }
 
 /// Set last name
-pub fn lastname(name: &str) -> PreambleElement {
+pub fn lastname<'a>(name: impl Into<TexStr<'a>>) -> PreambleElement {
+    let name = name.into();
     let s = texify!("lastname", name);
     PreambleElement::UserDefined(s)
 }
 
 /// Set given name (First name)
-pub fn givenname(name: &str) -> PreambleElement {
+pub fn givenname<'a>(name: impl Into<TexStr<'a>>) -> PreambleElement {
+    let name = name.into();
     let s = texify!("givenname", name);
     PreambleElement::UserDefined(s)
 }
 
 /// Set family name (Last name)
-pub fn familyname(name: &str) -> PreambleElement {
+pub fn familyname<'a>(name: impl Into<TexStr<'a>>) -> PreambleElement {
+    let name = name.into();
     let s = texify!("familyname", name);
     PreambleElement::UserDefined(s)
 }
 
 /// Set address
-pub fn address(street: &str, city: Option<&str>, country: Option<&str>) -> PreambleElement {
-    let mut s = texify!("address", street);
-    match city {
-        Some(city) => {
-            s.push_str(&format!("{{{}}}", city));
-        }
-        None => {
-            return PreambleElement::UserDefined(s);
-        }
-    }
-
-    if let Some(country) = country {
-        s.push_str(&format!("{{{}}}", country));
-    }
+pub fn address<'a>(
+    street: impl Into<TexStr<'a>>,
+    city: Option<TexStr<'a>>,
+    country: Option<TexStr<'a>>,
+) -> PreambleElement {
+    let street = street.into();
+    let s = match (city, country) {
+        (Some(city), Some(country)) => texify!("address", street, city, country),
+        (Some(city), None) => texify!("address", street, city),
+        (None, _) => texify!("address", street),
+    };
 
     PreambleElement::UserDefined(s)
 }
 
 /// Set mobile number
-pub fn mobile(number: &str) -> PreambleElement {
+pub fn mobile<'a>(number: impl Into<TexStr<'a>>) -> PreambleElement {
+    let number = number.into();
     let s = texify!("mobile", number);
     PreambleElement::UserDefined(s)
 }
 
 /// Set phone number
-pub fn phone(number: &str, phone_type: Option<PhoneType>) -> PreambleElement {
+pub fn phone<'a>(number: impl Into<TexStr<'a>>, phone_type: Option<PhoneType>) -> PreambleElement {
+    let number = number.into();
     let s = texify!("phone", [phone_type], number);
     PreambleElement::UserDefined(s)
 }
 
 /// Set fax number
-pub fn fax(number: &str) -> PreambleElement {
+pub fn fax<'a>(number: impl Into<TexStr<'a>>) -> PreambleElement {
+    let number = number.into();
     let s = texify!("fax", number);
     PreambleElement::UserDefined(s)
 }
 
 /// Set email address
-pub fn email(address: &str) -> PreambleElement {
+///
+/// It is also printed as text, so plain text is escaped with [`escape`](crate::tex::escape).
+pub fn email<'a>(address: impl Into<TexStr<'a>>) -> PreambleElement {
+    let address = address.into();
     let s = texify!("email", address);
     PreambleElement::UserDefined(s)
 }
 
 /// Set social link
-pub fn social(account: &str, social_type: SocialType, url: Option<&str>) -> PreambleElement {
-    let mut s = texify!("social");
-    s.push_str(&format!(r"[{}]", social_type));
-
-    if let Some(url) = url {
-        s.push_str(&format!(r"[{}]", url));
-    }
-
-    s.push_str(&format!(r"{{{}}}", account));
+///
+/// A plain text `url` is escaped as an URL, see [`escape_url`].
+pub fn social<'a>(
+    account: impl Into<TexStr<'a>>,
+    social_type: SocialType,
+    url: Option<TexStr<'a>>,
+) -> PreambleElement {
+    let (account, social_type) = (account.into(), Some(social_type));
+    let url = url.map(self::url);
+    let s = texify!("social", [social_type], [url], account);
 
     PreambleElement::UserDefined(s)
 }
 
 /// Set home page
-pub fn homepage(url: &str) -> PreambleElement {
+///
+/// It is also printed as text, so plain text is escaped with [`escape`](crate::tex::escape).
+pub fn homepage<'a>(url: impl Into<TexStr<'a>>) -> PreambleElement {
+    let url = url.into();
     let s = texify!("homepage", url);
     PreambleElement::UserDefined(s)
 }
@@ -354,20 +658,51 @@ pub fn cvtheme(style: Style, color: Option<Color>) -> PreambleElement {
     PreambleElement::UserDefined(s)
 }
 
+/// Set moderncv style (`\moderncvstyle`, moderncv 2.x)
+pub fn moderncvstyle(style: Style) -> PreambleElement {
+    let s = texify!("moderncvstyle", style);
+    PreambleElement::UserDefined(s)
+}
+
+/// Set moderncv color (`\moderncvcolor`, moderncv 2.x)
+pub fn moderncvcolor(color: Color) -> PreambleElement {
+    let s = texify!("moderncvcolor", color);
+    PreambleElement::UserDefined(s)
+}
+
+/// Override a theme color(`\definecolor{color1}{HTML}{1A2B3C}`), after `cvtheme`
+pub fn definecolor(slot: ColorSlot, color: &CustomColor) -> PreambleElement {
+    let (model, value) = (color.model(), color.value());
+    let s = texify!("definecolor", slot, model, value);
+    PreambleElement::UserDefined(s)
+}
+
 /// Set extra information
-pub fn extrainfo(info: &str) -> PreambleElement {
+pub fn extrainfo<'a>(info: impl Into<TexStr<'a>>) -> PreambleElement {
+    let info = info.into();
     let s = texify!("extrainfo", info);
     PreambleElement::UserDefined(s)
 }
 
 /// Set photo
+///
+/// The file name and the dimensions are passed to LaTeX unescaped.
 pub fn photo(photo: &str, width: Option<&str>, frame: Option<&str>) -> PreambleElement {
     let s = texify!("photo", [width], [frame], photo);
     PreambleElement::UserDefined(s)
 }
 
+/// Escape plain text for an URL which is not displayed
+fn url(url: TexStr<'_>) -> TexStr<'_> {
+    match url {
+        TexStr::Text(s) => TexStr::raw(escape_url(&s).into_owned()),
+        raw => raw,
+    }
+}
+
 /// Set quote string
-pub fn quote(msg: &str) -> PreambleElement {
+pub fn quote<'a>(msg: impl Into<TexStr<'a>>) -> PreambleElement {
+    let msg = msg.into();
     let s = texify!("quote", msg);
     PreambleElement::UserDefined(s)
 }
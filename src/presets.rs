@@ -0,0 +1,142 @@
+//! Standard preamble boilerplate of moderncv templates
+//!
+//! ```
+//! use latex::Document;
+//! use moderncv::presets::Presets;
+//! use moderncv::{document_class, CVPreamble, Engine};
+//!
+//! let mut doc = Document::new(document_class());
+//! doc.preamble.presets(Presets::new(Engine::Pdflatex).babel("english"));
+//! ```
+
+use crate::{texify, Engine};
+use latex::PreambleElement;
+
+/// Page layout set with the geometry package
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry {
+    /// Part of the paper used by the text, e.g. `0.75`
+    Scale(f32),
+    /// Margins as LaTeX lengths
+    Margins {
+        top: String,
+        bottom: String,
+        left: String,
+        right: String,
+    },
+}
+
+/// Packages and lengths every moderncv template sets up
+///
+/// The defaults follow the moderncv template: `inputenc` and `fontenc` for
+/// pdflatex (xelatex and lualatex read UTF-8 natively) and a page scale of 0.75.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Presets {
+    engine: Engine,
+    geometry: Option<Geometry>,
+    babel: Option<String>,
+    hintscolumnwidth: Option<String>,
+}
+
+impl Presets {
+    /// Create the default presets for `engine`
+    pub fn new(engine: Engine) -> Self {
+        Self {
+            engine,
+            geometry: Some(Geometry::Scale(0.75)),
+            babel: None,
+            hintscolumnwidth: None,
+        }
+    }
+
+    /// Set the page scale
+    pub fn scale(&mut self, scale: f32) -> &mut Self {
+        self.geometry = Some(Geometry::Scale(scale));
+
+        self
+    }
+
+    /// Set the page margins
+    pub fn margins(&mut self, top: &str, bottom: &str, left: &str, right: &str) -> &mut Self {
+        self.geometry = Some(Geometry::Margins {
+            top: top.to_string(),
+            bottom: bottom.to_string(),
+            left: left.to_string(),
+            right: right.to_string(),
+        });
+
+        self
+    }
+
+    /// Keep the default page layout of moderncv
+    pub fn no_geometry(&mut self) -> &mut Self {
+        self.geometry = None;
+
+        self
+    }
+
+    /// Load babel for `language`, e.g. `english` or `ngerman`
+    pub fn babel(&mut self, language: &str) -> &mut Self {
+        self.babel = Some(language.to_string());
+
+        self
+    }
+
+    /// Set the width of the column holding dates and headers, e.g. `3cm`
+    pub fn hintscolumnwidth(&mut self, width: &str) -> &mut Self {
+        self.hintscolumnwidth = Some(width.to_string());
+
+        self
+    }
+
+    /// The preamble elements in order
+    pub fn to_elements(&self) -> Vec<PreambleElement> {
+        let mut elements = Vec::new();
+
+        if !self.engine.is_unicode() {
+            elements.push(usepackage("inputenc", Some("utf8")));
+            elements.push(usepackage("fontenc", Some("T1")));
+        }
+
+        match &self.geometry {
+            Some(Geometry::Scale(scale)) => {
+                elements.push(usepackage("geometry", Some(&format!("scale={}", scale))));
+            }
+            Some(Geometry::Margins {
+                top,
+                bottom,
+                left,
+                right,
+            }) => {
+                let margins = format!(
+                    "top={},bottom={},left={},right={}",
+                    top, bottom, left, right
+                );
+                elements.push(usepackage("geometry", Some(&margins)));
+            }
+            None => {}
+        }
+
+        if let Some(language) = &self.babel {
+            elements.push(usepackage("babel", Some(language)));
+        }
+
+        if let Some(width) = &self.hintscolumnwidth {
+            let length = r"\hintscolumnwidth";
+            elements.push(PreambleElement::UserDefined(texify!(
+                "setlength",
+                length,
+                width
+            )));
+        }
+
+        elements
+    }
+}
+
+fn usepackage(package: &str, argument: Option<&str>) -> PreambleElement {
+    PreambleElement::UsePackage {
+        package: package.to_string(),
+        argument: argument.map(str::to_string),
+    }
+}
@@ -0,0 +1,120 @@
+//! Options of rendering a [`Resume`](crate::Resume)
+//!
+//! The helpers of `CVPreamble` and `CVSection` emit the command they are named
+//! after. A resume picks its commands from the options it is rendered with:
+//!
+//! ```
+//! use moderncv::{ModernCvVersion, RenderOptions, Resume};
+//!
+//! let resume = Resume::new();
+//!
+//! // `\name`, `\moderncvstyle`, `\cvitem`, ... instead of the 1.x commands
+//! let doc = resume
+//!     .render(RenderOptions::new().version(ModernCvVersion::V2))
+//!     .document;
+//! ```
+
+use crate::date::{DateFormat, Language};
+use crate::document::TitlePlacement;
+use crate::tex::TexStr;
+use crate::unicode::{UnicodePolicy, Unrepresentable};
+use crate::{Engine, ModernCvVersion};
+use latex::Document;
+
+/// How a resume is rendered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Targeted moderncv release, 1.x by default
+    pub version: ModernCvVersion,
+    /// Where the header (`\makecvtitle`) is printed
+    pub title: TitlePlacement,
+    /// Engine the text is encoded for, pdflatex by default
+    pub engine: Engine,
+    /// How non-ASCII characters are encoded
+    pub unicode: UnicodePolicy,
+    /// Format of date ranges, ISO by default
+    pub date_format: DateFormat,
+    /// Language of month names and of `present`, English by default
+    pub language: Language,
+    /// Read the plain text of entries and the quote as inline Markdown
+    #[cfg(feature = "markdown")]
+    pub markdown: bool,
+}
+
+impl RenderOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Target a moderncv release
+    pub fn version(&mut self, version: ModernCvVersion) -> &mut Self {
+        self.version = version;
+
+        self
+    }
+
+    /// Print the header at `placement`
+    pub fn title(&mut self, placement: TitlePlacement) -> &mut Self {
+        self.title = placement;
+
+        self
+    }
+
+    /// Encode the text for `engine`
+    pub fn engine(&mut self, engine: Engine) -> &mut Self {
+        self.engine = engine;
+
+        self
+    }
+
+    /// Encode non-ASCII characters with `policy`
+    pub fn unicode(&mut self, policy: UnicodePolicy) -> &mut Self {
+        self.unicode = policy;
+
+        self
+    }
+
+    /// Render date ranges in `format`
+    pub fn date_format(&mut self, format: DateFormat) -> &mut Self {
+        self.date_format = format;
+
+        self
+    }
+
+    /// Render month names and `present` in `language`
+    pub fn language(&mut self, language: Language) -> &mut Self {
+        self.language = language;
+
+        self
+    }
+
+    /// Read free text as inline Markdown
+    #[cfg(feature = "markdown")]
+    pub fn markdown(&mut self, enabled: bool) -> &mut Self {
+        self.markdown = enabled;
+
+        self
+    }
+
+    /// Convert plain `text` from Markdown if enabled
+    pub(crate) fn free_text<'a>(&self, text: &'a TexStr<'_>) -> TexStr<'a> {
+        #[cfg(feature = "markdown")]
+        {
+            if let TexStr::Text(s) = text {
+                if self.markdown {
+                    return TexStr::markdown(s);
+                }
+            }
+        }
+
+        text.into()
+    }
+}
+
+/// A rendered document and the characters which could not be encoded
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rendered {
+    pub document: Document,
+    pub unrepresentable: Vec<Unrepresentable>,
+}
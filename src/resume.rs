@@ -0,0 +1,643 @@
+use crate::chronology::{self, Issue, Order, Span};
+use crate::date::{DateRange, Years};
+use crate::description::Description;
+use crate::document::{CVDocument, TitlePlacement};
+use crate::letter::Letter;
+use crate::metadata::Metadata;
+use crate::preamble::{Color, ColorSlot, CustomColor, PhoneType, SocialType, Style};
+use crate::render::{RenderOptions, Rendered};
+use crate::section;
+use crate::tex::{escape, TexStr};
+use crate::unicode;
+use crate::{document_class, CVPreamble, ModernCvVersion};
+use latex::{Document, Element, Section};
+
+/// Moderncv theme
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Theme {
+    pub style: Style,
+    pub color: Option<Color>,
+    /// Overrides the main color of `color`
+    pub color1: Option<CustomColor>,
+    /// Overrides the secondary color of `color`
+    pub color2: Option<CustomColor>,
+}
+
+/// Photo shown in the resume header
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Photo {
+    pub file: String,
+    pub width: Option<String>,
+    pub frame: Option<String>,
+}
+
+/// Personal information
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Personal {
+    pub firstname: TexStr<'static>,
+    pub lastname: TexStr<'static>,
+    pub photo: Option<Photo>,
+    pub quote: Option<TexStr<'static>>,
+    pub extrainfo: Option<TexStr<'static>>,
+}
+
+/// Postal address
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Address {
+    pub street: TexStr<'static>,
+    pub city: Option<TexStr<'static>>,
+    pub country: Option<TexStr<'static>>,
+}
+
+/// Phone number
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Phone {
+    pub number: TexStr<'static>,
+    pub phone_type: Option<PhoneType>,
+}
+
+/// Social media account
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Social {
+    pub account: TexStr<'static>,
+    pub social_type: SocialType,
+    pub url: Option<TexStr<'static>>,
+}
+
+/// Contact information
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Contact {
+    pub address: Option<Address>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub phones: Vec<Phone>,
+    pub email: Option<TexStr<'static>>,
+    pub homepage: Option<TexStr<'static>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub socials: Vec<Social>,
+}
+
+/// An entry of a resume section, one variant per moderncv command
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)
+)]
+pub enum CVEntry {
+    /// Typical resume job / education entry (`\cventry`)
+    Entry {
+        years: Years,
+        job: TexStr<'static>,
+        employer: TexStr<'static>,
+        localization: TexStr<'static>,
+        grade: Option<TexStr<'static>>,
+        comment: Option<Description>,
+    },
+    /// Language skills (`\cvlanguage`)
+    Language {
+        name: TexStr<'static>,
+        level: TexStr<'static>,
+        comment: TexStr<'static>,
+    },
+    /// Computer skills (`\cvcomputer`)
+    Computer {
+        category1: TexStr<'static>,
+        programs1: TexStr<'static>,
+        category2: TexStr<'static>,
+        programs2: TexStr<'static>,
+    },
+    /// Header and text (`\cvline`)
+    Line {
+        leftmark: TexStr<'static>,
+        text: TexStr<'static>,
+    },
+    /// Header and text (`\cvitem`)
+    Item {
+        header: TexStr<'static>,
+        text: TexStr<'static>,
+    },
+    /// Two headers and their text (`\cvdoubleitem`)
+    DoubleItem {
+        header1: TexStr<'static>,
+        text1: TexStr<'static>,
+        header2: TexStr<'static>,
+        text2: TexStr<'static>,
+    },
+    /// List item (`\cvlistitem`)
+    ListItem { item: TexStr<'static> },
+    /// Two list items (`\cvlistdoubleitem`)
+    ListDoubleItem {
+        item1: TexStr<'static>,
+        item2: TexStr<'static>,
+    },
+    /// Header and text with a proficiency comment (`\cvitemwithcomment`)
+    ItemWithComment {
+        header: TexStr<'static>,
+        text: TexStr<'static>,
+        comment: TexStr<'static>,
+    },
+    /// Group of entries under a heading (`\subsection`)
+    Subsection {
+        title: String,
+        #[cfg_attr(feature = "serde", serde(default))]
+        entries: Vec<CVEntry>,
+    },
+    /// Page break (`\pagebreak{}`)
+    PageBreak,
+    /// New page (`\newpage{}`)
+    NewPage,
+    /// Vertical space, `length` is a LaTeX length like `-0.5em` (`\vspace`)
+    VSpace { length: String },
+}
+
+impl CVEntry {
+    /// The date range of a `\cventry`, `None` for undated years and other entries
+    pub fn dates(&self) -> Option<DateRange> {
+        match self {
+            Self::Entry { years, .. } => years.range(),
+            _ => None,
+        }
+    }
+
+    /// Sort the entries of a subsection
+    fn sort_chronologically(&mut self, order: Order) {
+        if let Self::Subsection { entries, .. } = self {
+            chronology::sort(entries, order, CVEntry::dates);
+            for entry in entries {
+                entry.sort_chronologically(order);
+            }
+        }
+    }
+
+    /// Dated entries, those of a subsection included
+    fn spans(&self, spans: &mut Vec<Span>) {
+        match self {
+            Self::Entry {
+                years,
+                job,
+                employer,
+                ..
+            } => {
+                let range = match years.range() {
+                    Some(range) => range,
+                    None => return,
+                };
+                let label = [job, employer]
+                    .iter()
+                    .map(|s| s.as_str())
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                spans.push(Span { label, range });
+            }
+            Self::Subsection { entries, .. } => {
+                for entry in entries {
+                    entry.spans(spans);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Render the entry with the matching `section` helper
+    ///
+    /// A subsection renders its heading and entries on separate lines.
+    pub fn to_element(&self) -> Element {
+        self.to_element_with(&RenderOptions::default())
+    }
+
+    /// Render the entry with the commands and the Markdown setting of `options`
+    pub(crate) fn to_element_with(&self, options: &RenderOptions) -> Element {
+        let v2 = options.version == ModernCvVersion::V2;
+        let free_text = |text| options.free_text(text);
+        match self {
+            Self::Entry {
+                years,
+                job,
+                employer,
+                localization,
+                grade,
+                comment,
+            } => section::cventry(
+                years.to_tex(options.date_format, options.language),
+                free_text(job),
+                free_text(employer),
+                free_text(localization),
+                grade.as_ref().map(free_text),
+                comment.as_ref().map(|comment| comment.to_tex_with(options)),
+            ),
+            Self::Language {
+                name,
+                level,
+                comment,
+            } if v2 => {
+                section::cvitemwithcomment(free_text(name), free_text(level), free_text(comment))
+            }
+            Self::Language {
+                name,
+                level,
+                comment,
+            } => section::cvlanguage(free_text(name), free_text(level), free_text(comment)),
+            Self::Computer {
+                category1,
+                programs1,
+                category2,
+                programs2,
+            } if v2 => section::cvdoubleitem(
+                free_text(category1),
+                free_text(programs1),
+                free_text(category2),
+                free_text(programs2),
+            ),
+            Self::Computer {
+                category1,
+                programs1,
+                category2,
+                programs2,
+            } => section::cvcomputer(
+                free_text(category1),
+                free_text(programs1),
+                free_text(category2),
+                free_text(programs2),
+            ),
+            Self::Line { leftmark, text } if v2 => {
+                section::cvitem(free_text(leftmark), free_text(text))
+            }
+            Self::Line { leftmark, text } => section::cvline(free_text(leftmark), free_text(text)),
+            Self::Item { header, text } => section::cvitem(free_text(header), free_text(text)),
+            Self::DoubleItem {
+                header1,
+                text1,
+                header2,
+                text2,
+            } => section::cvdoubleitem(
+                free_text(header1),
+                free_text(text1),
+                free_text(header2),
+                free_text(text2),
+            ),
+            Self::ListItem { item } => section::cvlistitem(free_text(item)),
+            Self::ListDoubleItem { item1, item2 } => {
+                section::cvlistdoubleitem(free_text(item1), free_text(item2))
+            }
+            Self::ItemWithComment {
+                header,
+                text,
+                comment,
+            } => section::cvitemwithcomment(free_text(header), free_text(text), free_text(comment)),
+            Self::Subsection { title, entries } => {
+                let lines = std::iter::once(section::subsection(title))
+                    .chain(entries.iter().map(|entry| entry.to_element_with(options)))
+                    .filter_map(|elem| match elem {
+                        Element::UserDefined(s) => Some(s),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                Element::UserDefined(lines.join("\n"))
+            }
+            Self::PageBreak => section::pagebreak(),
+            Self::NewPage => section::newpage(),
+            Self::VSpace { length } => section::vspace(length),
+        }
+    }
+}
+
+/// A resume section holding its entries in order
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct ResumeSection {
+    pub title: String,
+    /// Sort the dated entries when rendering, in insertion order by default
+    pub order: Option<Order>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub entries: Vec<CVEntry>,
+}
+
+impl ResumeSection {
+    /// Create a new section
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            order: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add an entry to the section
+    pub fn push(&mut self, entry: CVEntry) -> &mut Self {
+        self.entries.push(entry);
+
+        self
+    }
+
+    /// Sort the dated entries, within each subsection
+    ///
+    /// Entries without a date range keep their position.
+    pub fn sort_chronologically(&mut self, order: Order) -> &mut Self {
+        chronology::sort(&mut self.entries, order, CVEntry::dates);
+        for entry in &mut self.entries {
+            entry.sort_chronologically(order);
+        }
+
+        self
+    }
+
+    /// Report gaps longer than `max_gap_months` and overlaps between dated entries
+    pub fn lint(&self, max_gap_months: u32) -> Vec<Issue> {
+        let mut spans = Vec::new();
+        for entry in &self.entries {
+            entry.spans(&mut spans);
+        }
+
+        chronology::lint(&spans, max_gap_months)
+    }
+
+    /// Render the section as `latex::Section`, sorted if `order` is set
+    pub fn to_section(&self) -> Section {
+        self.to_section_with(&RenderOptions::default())
+    }
+
+    pub(crate) fn to_section_with(&self, options: &RenderOptions) -> Section {
+        let mut section = Section::new(&escape(&self.title));
+        for (_, entry) in self.rendered_entries() {
+            section.push(entry.to_element_with(options));
+        }
+
+        section
+    }
+
+    /// The entries in the order they are rendered, with their index in `entries`
+    pub(crate) fn rendered_entries(&self) -> Vec<(usize, CVEntry)> {
+        let mut entries = self.entries.iter().cloned().enumerate().collect::<Vec<_>>();
+        if let Some(order) = self.order {
+            chronology::sort(&mut entries, order, |(_, entry)| entry.dates());
+            for (_, entry) in &mut entries {
+                entry.sort_chronologically(order);
+            }
+        }
+
+        entries
+    }
+}
+
+/// A resume: personal information, contact information and ordered sections
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Resume {
+    pub title: Option<String>,
+    pub theme: Option<Theme>,
+    pub personal: Personal,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub contact: Contact,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sections: Vec<ResumeSection>,
+    /// PDF metadata, the title and author default to the resume's
+    pub metadata: Option<Metadata>,
+}
+
+impl Resume {
+    /// Create an empty resume
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a section to the resume
+    pub fn push(&mut self, section: ResumeSection) -> &mut Self {
+        self.sections.push(section);
+
+        self
+    }
+
+    /// Render the resume as a moderncv `latex::Document`
+    pub fn to_document(&self) -> Document {
+        self.render(&RenderOptions::default()).document
+    }
+
+    /// Render the resume with the header (`\makecvtitle`) at `placement`
+    pub fn to_document_with_title(&self, placement: TitlePlacement) -> Document {
+        self.render(RenderOptions::new().title(placement)).document
+    }
+
+    /// Render the resume with `options`
+    pub fn render(&self, options: &RenderOptions) -> Rendered {
+        encode(self.body(options), options)
+    }
+
+    /// Render a cover letter with the personal information of the resume
+    pub fn to_letter(&self, letter: &Letter) -> Document {
+        self.render_letter(letter, &RenderOptions::default())
+            .document
+    }
+
+    /// Render a cover letter with `options`
+    pub fn render_letter(&self, letter: &Letter, options: &RenderOptions) -> Rendered {
+        let mut doc = self.header(options);
+        for elem in letter.to_elements() {
+            doc.push(elem);
+        }
+
+        encode(doc, options)
+    }
+
+    /// Render the resume followed by the cover letter in one document
+    pub fn to_document_with_letter(&self, letter: &Letter) -> Document {
+        self.render_with_letter(letter, &RenderOptions::default())
+            .document
+    }
+
+    /// Render the resume followed by the cover letter with `options`
+    pub fn render_with_letter(&self, letter: &Letter, options: &RenderOptions) -> Rendered {
+        let mut doc = self.body(options);
+        doc.push(Element::UserDefined(r"\clearpage".to_string()));
+        for elem in letter.to_elements() {
+            doc.push(elem);
+        }
+
+        encode(doc, options)
+    }
+
+    /// The header and the sections, not encoded yet
+    fn body(&self, options: &RenderOptions) -> Document {
+        let mut doc = self.header(options);
+
+        let index = match options.title {
+            TitlePlacement::Start => Some(0),
+            TitlePlacement::BeforeSection(i) => Some(i.min(self.sections.len())),
+            TitlePlacement::Manual => None,
+        };
+
+        for (i, section) in self.sections.iter().enumerate() {
+            if index == Some(i) {
+                doc.makecvtitle();
+            }
+            doc.push(section.to_section_with(options));
+        }
+
+        if index == Some(self.sections.len()) {
+            doc.makecvtitle();
+        }
+
+        doc
+    }
+
+    fn header(&self, options: &RenderOptions) -> Document {
+        let v2 = options.version == ModernCvVersion::V2;
+        let mut doc = Document::new(document_class());
+        let preamble = &mut doc.preamble;
+
+        if let Some(title) = &self.title {
+            preamble.title(&escape(title));
+        }
+
+        if let Some(theme) = &self.theme {
+            if v2 {
+                preamble.moderncvstyle(theme.style);
+                if let Some(color) = theme.color {
+                    preamble.moderncvcolor(color);
+                }
+            } else {
+                preamble.cvtheme(theme.style, theme.color);
+            }
+
+            if let Some(color1) = &theme.color1 {
+                preamble.definecolor(ColorSlot::Color1, color1);
+            }
+
+            if let Some(color2) = &theme.color2 {
+                preamble.definecolor(ColorSlot::Color2, color2);
+            }
+        }
+
+        let personal = &self.personal;
+        if v2 {
+            preamble.name(&personal.firstname, &personal.lastname);
+        } else {
+            preamble
+                .firstname(&personal.firstname)
+                .familyname(&personal.lastname);
+        }
+
+        if let Some(photo) = &personal.photo {
+            preamble.photo(&photo.file, photo.width.as_deref(), photo.frame.as_deref());
+        }
+
+        let contact = &self.contact;
+        if let Some(address) = &contact.address {
+            preamble.address(
+                &address.street,
+                address.city.as_ref().map(TexStr::from),
+                address.country.as_ref().map(TexStr::from),
+            );
+        }
+
+        for phone in &contact.phones {
+            preamble.phone(&phone.number, phone.phone_type);
+        }
+
+        if let Some(email) = &contact.email {
+            preamble.email(email);
+        }
+
+        for social in &contact.socials {
+            preamble.social(
+                &social.account,
+                social.social_type,
+                social.url.as_ref().map(TexStr::from),
+            );
+        }
+
+        if let Some(homepage) = &contact.homepage {
+            preamble.homepage(homepage);
+        }
+
+        if let Some(extrainfo) = &personal.extrainfo {
+            preamble.extrainfo(extrainfo);
+        }
+
+        if let Some(quote) = &personal.quote {
+            preamble.quote(options.free_text(quote));
+        }
+
+        if let Some(metadata) = &self.metadata {
+            let mut metadata = metadata.clone();
+            if metadata.author.is_none() {
+                let name = [&personal.firstname, &personal.lastname]
+                    .iter()
+                    .map(ToString::to_string)
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>();
+                if !name.is_empty() {
+                    metadata.author(TexStr::raw(name.join(" ")));
+                }
+            }
+            preamble.metadata(&metadata);
+        }
+
+        doc
+    }
+}
+
+/// Encode `doc` for the engine of `options`
+fn encode(doc: Document, options: &RenderOptions) -> Rendered {
+    let (document, unrepresentable) =
+        unicode::encode_document(&doc, options.engine, options.unicode);
+
+    Rendered {
+        document,
+        unrepresentable,
+    }
+}
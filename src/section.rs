@@ -1,44 +1,119 @@
 use super::texify;
+use crate::tex::TexStr;
 use latex::{Element, Section};
 
 /// A trait which implement useful functions for moderncv section
 pub trait CVSection {
-    fn cventry(
-        &mut self,
-        year: &str,
-        job: &str,
-        employer: &str,
-        localization: &str,
-        grade: Option<&str>,
-        comment: Option<&str>,
+    fn cventry<'a>(
+        &mut self,
+        year: impl Into<TexStr<'a>>,
+        job: impl Into<TexStr<'a>>,
+        employer: impl Into<TexStr<'a>>,
+        localization: impl Into<TexStr<'a>>,
+        grade: Option<TexStr<'a>>,
+        comment: Option<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvlanguage<'a>(
+        &mut self,
+        name: impl Into<TexStr<'a>>,
+        level: impl Into<TexStr<'a>>,
+        comment: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvcomputer<'a>(
+        &mut self,
+        category: impl Into<TexStr<'a>>,
+        programs: impl Into<TexStr<'a>>,
+        category: impl Into<TexStr<'a>>,
+        programs: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvline<'a>(
+        &mut self,
+        leftmark: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvitem<'a>(
+        &mut self,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvdoubleitem<'a>(
+        &mut self,
+        header1: impl Into<TexStr<'a>>,
+        text1: impl Into<TexStr<'a>>,
+        header2: impl Into<TexStr<'a>>,
+        text2: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvlistitem<'a>(&mut self, item: impl Into<TexStr<'a>>) -> &mut Self;
+    fn cvlistdoubleitem<'a>(
+        &mut self,
+        item1: impl Into<TexStr<'a>>,
+        item2: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvitemwithcomment<'a>(
+        &mut self,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+        comment: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    #[allow(clippy::too_many_arguments)]
+    fn cventry_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        year: impl Into<TexStr<'a>>,
+        job: impl Into<TexStr<'a>>,
+        employer: impl Into<TexStr<'a>>,
+        localization: impl Into<TexStr<'a>>,
+        grade: Option<TexStr<'a>>,
+        comment: Option<TexStr<'a>>,
     ) -> &mut Self;
-    fn cvlanguage(&mut self, name: &str, level: &str, comment: &str) -> &mut Self;
-    fn cvcomputer(
+    fn cvitem_with_spacing<'a>(
         &mut self,
-        category: &str,
-        programs: &str,
-        category: &str,
-        programs: &str,
+        spacing: Option<&str>,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
     ) -> &mut Self;
-    fn cvline(&mut self, leftmark: &str, text: &str) -> &mut Self;
-    fn cvitem(&mut self, header: &str, text: &str) -> &mut Self;
-    fn cvdoubleitem(&mut self, header1: &str, text1: &str, header2: &str, text2: &str)
-        -> &mut Self;
-    fn cvlistitem(&mut self, item: &str) -> &mut Self;
-    fn cvlistdoubleitem(&mut self, item1: &str, item2: &str) -> &mut Self;
-    fn cvitemwithcomment(&mut self, header: &str, text: &str, comment: &str) -> &mut Self;
+    fn cvdoubleitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        header1: impl Into<TexStr<'a>>,
+        text1: impl Into<TexStr<'a>>,
+        header2: impl Into<TexStr<'a>>,
+        text2: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvlistitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        item: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvlistdoubleitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        item1: impl Into<TexStr<'a>>,
+        item2: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn cvitemwithcomment_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+        comment: impl Into<TexStr<'a>>,
+    ) -> &mut Self;
+    fn subsection(&mut self, subsection: &Section) -> &mut Self;
+    fn pagebreak(&mut self) -> &mut Self;
+    fn newpage(&mut self) -> &mut Self;
+    fn vspace(&mut self, length: &str) -> &mut Self;
 }
 
 impl CVSection for Section {
     /// Make a typical resume job / education entry
-    fn cventry(
-        &mut self,
-        years: &str,
-        job: &str,
-        employer: &str,
-        localization: &str,
-        grade: Option<&str>,
-        comment: Option<&str>,
+    fn cventry<'a>(
+        &mut self,
+        years: impl Into<TexStr<'a>>,
+        job: impl Into<TexStr<'a>>,
+        employer: impl Into<TexStr<'a>>,
+        localization: impl Into<TexStr<'a>>,
+        grade: Option<TexStr<'a>>,
+        comment: Option<TexStr<'a>>,
     ) -> &mut Self {
         let elem = self::cventry(years, job, employer, localization, grade, comment);
         self.push(elem);
@@ -47,7 +122,12 @@ impl CVSection for Section {
     }
 
     /// Make a resume entry to describe language skills
-    fn cvlanguage(&mut self, name: &str, level: &str, comment: &str) -> &mut Self {
+    fn cvlanguage<'a>(
+        &mut self,
+        name: impl Into<TexStr<'a>>,
+        level: impl Into<TexStr<'a>>,
+        comment: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::cvlanguage(name, level, comment);
         self.push(elem);
 
@@ -55,12 +135,12 @@ impl CVSection for Section {
     }
 
     /// Make a resume entry to describe computer skills
-    fn cvcomputer(
+    fn cvcomputer<'a>(
         &mut self,
-        category1: &str,
-        programs1: &str,
-        category2: &str,
-        programs2: &str,
+        category1: impl Into<TexStr<'a>>,
+        programs1: impl Into<TexStr<'a>>,
+        category2: impl Into<TexStr<'a>>,
+        programs2: impl Into<TexStr<'a>>,
     ) -> &mut Self {
         let elem = self::cvcomputer(category1, programs1, category2, programs2);
         self.push(elem);
@@ -69,7 +149,11 @@ impl CVSection for Section {
     }
 
     /// Make a resume line with a header and a corresponding text (Alias of `cvitem`)
-    fn cvline(&mut self, leftmark: &str, text: &str) -> &mut Self {
+    fn cvline<'a>(
+        &mut self,
+        leftmark: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::cvline(leftmark, text);
         self.push(elem);
 
@@ -77,7 +161,11 @@ impl CVSection for Section {
     }
 
     /// Make a resume line with a header and a corresponding text
-    fn cvitem(&mut self, header: &str, text: &str) -> &mut Self {
+    fn cvitem<'a>(
+        &mut self,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::cvitem(header, text);
         self.push(elem);
 
@@ -85,12 +173,12 @@ impl CVSection for Section {
     }
 
     /// Make a resume line with two headers and their corresponding text
-    fn cvdoubleitem(
+    fn cvdoubleitem<'a>(
         &mut self,
-        header1: &str,
-        text1: &str,
-        header2: &str,
-        text2: &str,
+        header1: impl Into<TexStr<'a>>,
+        text1: impl Into<TexStr<'a>>,
+        header2: impl Into<TexStr<'a>>,
+        text2: impl Into<TexStr<'a>>,
     ) -> &mut Self {
         let elem = self::cvdoubleitem(header1, text1, header2, text2);
         self.push(elem);
@@ -99,7 +187,7 @@ impl CVSection for Section {
     }
 
     /// Make a resume line with a list item
-    fn cvlistitem(&mut self, item: &str) -> &mut Self {
+    fn cvlistitem<'a>(&mut self, item: impl Into<TexStr<'a>>) -> &mut Self {
         let elem = self::cvlistitem(item);
         self.push(elem);
 
@@ -107,7 +195,11 @@ impl CVSection for Section {
     }
 
     /// Make a resume line with two list items
-    fn cvlistdoubleitem(&mut self, item1: &str, item2: &str) -> &mut Self {
+    fn cvlistdoubleitem<'a>(
+        &mut self,
+        item1: impl Into<TexStr<'a>>,
+        item2: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::cvlistdoubleitem(item1, item2);
         self.push(elem);
 
@@ -115,82 +207,327 @@ impl CVSection for Section {
     }
 
     /// Make a resume entry with a proficiency comment
-    fn cvitemwithcomment(&mut self, header: &str, text: &str, comment: &str) -> &mut Self {
+    fn cvitemwithcomment<'a>(
+        &mut self,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+        comment: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
         let elem = self::cvitemwithcomment(header, text, comment);
         self.push(elem);
 
         self
     }
-}
 
-/// Make a typical resume job / education entry
-pub fn cventry(
-    years: &str,
-    job: &str,
-    employer: &str,
-    localization: &str,
-    grade: Option<&str>,
-    comment: Option<&str>,
-) -> Element {
-    let mut s = texify!("cventry", years, job, employer, localization);
+    /// Make a typical resume job / education entry with a spacing (`\cventry[-0.5em]`)
+    fn cventry_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        years: impl Into<TexStr<'a>>,
+        job: impl Into<TexStr<'a>>,
+        employer: impl Into<TexStr<'a>>,
+        localization: impl Into<TexStr<'a>>,
+        grade: Option<TexStr<'a>>,
+        comment: Option<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem =
+            self::cventry_with_spacing(spacing, years, job, employer, localization, grade, comment);
+        self.push(elem);
+
+        self
+    }
+
+    /// Make a resume line with a header, a corresponding text and a spacing
+    fn cvitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::cvitem_with_spacing(spacing, header, text);
+        self.push(elem);
 
-    match grade {
-        Some(grade) => s.push_str(&format!(r"{{{}}}", grade)),
-        None => s.push_str(r"{}"),
+        self
+    }
+
+    /// Make a resume line with two headers, their corresponding text and a spacing
+    fn cvdoubleitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        header1: impl Into<TexStr<'a>>,
+        text1: impl Into<TexStr<'a>>,
+        header2: impl Into<TexStr<'a>>,
+        text2: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::cvdoubleitem_with_spacing(spacing, header1, text1, header2, text2);
+        self.push(elem);
+
+        self
     }
 
-    match comment {
-        Some(comment) => s.push_str(&format!(r"{{{}}}", comment)),
-        None => s.push_str(r"{}"),
+    /// Make a resume line with a list item and a spacing
+    fn cvlistitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        item: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::cvlistitem_with_spacing(spacing, item);
+        self.push(elem);
+
+        self
     }
 
+    /// Make a resume line with two list items and a spacing
+    fn cvlistdoubleitem_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        item1: impl Into<TexStr<'a>>,
+        item2: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::cvlistdoubleitem_with_spacing(spacing, item1, item2);
+        self.push(elem);
+
+        self
+    }
+
+    /// Make a resume entry with a proficiency comment and a spacing
+    fn cvitemwithcomment_with_spacing<'a>(
+        &mut self,
+        spacing: Option<&str>,
+        header: impl Into<TexStr<'a>>,
+        text: impl Into<TexStr<'a>>,
+        comment: impl Into<TexStr<'a>>,
+    ) -> &mut Self {
+        let elem = self::cvitemwithcomment_with_spacing(spacing, header, text, comment);
+        self.push(elem);
+
+        self
+    }
+
+    /// Add a subsection (`\subsection`) followed by its elements
+    ///
+    /// The name of `subsection` is escaped as plain text, like subsection titles of a resume.
+    fn subsection(&mut self, subsection: &Section) -> &mut Self {
+        self.push(self::subsection(subsection.name.as_str()));
+        for elem in subsection.iter() {
+            self.push(elem.clone());
+        }
+
+        self
+    }
+
+    /// Break the page after the current line (`\pagebreak{}`)
+    fn pagebreak(&mut self) -> &mut Self {
+        self.push(self::pagebreak());
+
+        self
+    }
+
+    /// Start a new page (`\newpage{}`)
+    fn newpage(&mut self) -> &mut Self {
+        self.push(self::newpage());
+
+        self
+    }
+
+    /// Add vertical space (`\vspace{-0.5em}`)
+    fn vspace(&mut self, length: &str) -> &mut Self {
+        self.push(self::vspace(length));
+
+        self
+    }
+}
+
+/// Make a typical resume job / education entry
+pub fn cventry<'a>(
+    years: impl Into<TexStr<'a>>,
+    job: impl Into<TexStr<'a>>,
+    employer: impl Into<TexStr<'a>>,
+    localization: impl Into<TexStr<'a>>,
+    grade: Option<TexStr<'a>>,
+    comment: Option<TexStr<'a>>,
+) -> Element {
+    self::cventry_with_spacing(None, years, job, employer, localization, grade, comment)
+}
+
+/// Make a typical resume job / education entry with a spacing (`\cventry[-0.5em]`)
+pub fn cventry_with_spacing<'a>(
+    spacing: Option<&str>,
+    years: impl Into<TexStr<'a>>,
+    job: impl Into<TexStr<'a>>,
+    employer: impl Into<TexStr<'a>>,
+    localization: impl Into<TexStr<'a>>,
+    grade: Option<TexStr<'a>>,
+    comment: Option<TexStr<'a>>,
+) -> Element {
+    let (years, job, employer, localization) = (
+        years.into(),
+        job.into(),
+        employer.into(),
+        localization.into(),
+    );
+    let (grade, comment) = (grade.unwrap_or_default(), comment.unwrap_or_default());
+    let s = texify!(
+        "cventry",
+        [spacing],
+        years,
+        job,
+        employer,
+        localization,
+        grade,
+        comment
+    );
+
     Element::UserDefined(s)
 }
 
 /// Make a resume entry to describe language skills
-pub fn cvlanguage(name: &str, level: &str, comment: &str) -> Element {
+pub fn cvlanguage<'a>(
+    name: impl Into<TexStr<'a>>,
+    level: impl Into<TexStr<'a>>,
+    comment: impl Into<TexStr<'a>>,
+) -> Element {
+    let (name, level, comment) = (name.into(), level.into(), comment.into());
     let s = texify!("cvlanguage", name, level, comment);
     Element::UserDefined(s)
 }
 
 /// Make a resume entry to describe computer skills
-pub fn cvcomputer(category1: &str, programs1: &str, category2: &str, programs2: &str) -> Element {
+pub fn cvcomputer<'a>(
+    category1: impl Into<TexStr<'a>>,
+    programs1: impl Into<TexStr<'a>>,
+    category2: impl Into<TexStr<'a>>,
+    programs2: impl Into<TexStr<'a>>,
+) -> Element {
+    let (category1, programs1, category2, programs2) = (
+        category1.into(),
+        programs1.into(),
+        category2.into(),
+        programs2.into(),
+    );
     let s = texify!("cvcomputer", category1, programs1, category2, programs2);
     Element::UserDefined(s)
 }
 
 /// Make a resume line with a header and a corresponding text (Alias of `cvitem`)
-pub fn cvline(header: &str, text: &str) -> Element {
+pub fn cvline<'a>(header: impl Into<TexStr<'a>>, text: impl Into<TexStr<'a>>) -> Element {
+    let (header, text) = (header.into(), text.into());
     let s = texify!("cvline", header, text);
     Element::UserDefined(s)
 }
 
 /// Make a resume line with a header and a corresponding text
-pub fn cvitem(header: &str, text: &str) -> Element {
-    let s = texify!("cvitem", header, text);
+pub fn cvitem<'a>(header: impl Into<TexStr<'a>>, text: impl Into<TexStr<'a>>) -> Element {
+    self::cvitem_with_spacing(None, header, text)
+}
+
+/// Make a resume line with a header, a corresponding text and a spacing
+pub fn cvitem_with_spacing<'a>(
+    spacing: Option<&str>,
+    header: impl Into<TexStr<'a>>,
+    text: impl Into<TexStr<'a>>,
+) -> Element {
+    let (header, text) = (header.into(), text.into());
+    let s = texify!("cvitem", [spacing], header, text);
     Element::UserDefined(s)
 }
 
 /// Make a resume line with two headers and their corresponding text
-pub fn cvdoubleitem(header1: &str, text1: &str, header2: &str, text2: &str) -> Element {
-    let s = texify!("cvdoubleitem", header1, text1, header2, text2);
+pub fn cvdoubleitem<'a>(
+    header1: impl Into<TexStr<'a>>,
+    text1: impl Into<TexStr<'a>>,
+    header2: impl Into<TexStr<'a>>,
+    text2: impl Into<TexStr<'a>>,
+) -> Element {
+    self::cvdoubleitem_with_spacing(None, header1, text1, header2, text2)
+}
+
+/// Make a resume line with two headers, their corresponding text and a spacing
+pub fn cvdoubleitem_with_spacing<'a>(
+    spacing: Option<&str>,
+    header1: impl Into<TexStr<'a>>,
+    text1: impl Into<TexStr<'a>>,
+    header2: impl Into<TexStr<'a>>,
+    text2: impl Into<TexStr<'a>>,
+) -> Element {
+    let (header1, text1, header2, text2) =
+        (header1.into(), text1.into(), header2.into(), text2.into());
+    let s = texify!("cvdoubleitem", [spacing], header1, text1, header2, text2);
     Element::UserDefined(s)
 }
 
 /// Make a resume line with a list item
-pub fn cvlistitem(item: &str) -> Element {
-    let s = texify!("cvlistitem", item);
+pub fn cvlistitem<'a>(item: impl Into<TexStr<'a>>) -> Element {
+    self::cvlistitem_with_spacing(None, item)
+}
+
+/// Make a resume line with a list item and a spacing
+pub fn cvlistitem_with_spacing<'a>(spacing: Option<&str>, item: impl Into<TexStr<'a>>) -> Element {
+    let item = item.into();
+    let s = texify!("cvlistitem", [spacing], item);
     Element::UserDefined(s)
 }
 
 /// Make a resume line with two list items
-pub fn cvlistdoubleitem(item1: &str, item2: &str) -> Element {
-    let s = texify!("cvlistdoubleitem", item1, item2);
+pub fn cvlistdoubleitem<'a>(item1: impl Into<TexStr<'a>>, item2: impl Into<TexStr<'a>>) -> Element {
+    self::cvlistdoubleitem_with_spacing(None, item1, item2)
+}
+
+/// Make a resume line with two list items and a spacing
+pub fn cvlistdoubleitem_with_spacing<'a>(
+    spacing: Option<&str>,
+    item1: impl Into<TexStr<'a>>,
+    item2: impl Into<TexStr<'a>>,
+) -> Element {
+    let (item1, item2) = (item1.into(), item2.into());
+    let s = texify!("cvlistdoubleitem", [spacing], item1, item2);
     Element::UserDefined(s)
 }
 
 /// Make a resume entry with a proficiency comment
-pub fn cvitemwithcomment(header: &str, text: &str, comment: &str) -> Element {
-    let s = texify!("cvitemwithcomment", header, text, comment);
+pub fn cvitemwithcomment<'a>(
+    header: impl Into<TexStr<'a>>,
+    text: impl Into<TexStr<'a>>,
+    comment: impl Into<TexStr<'a>>,
+) -> Element {
+    self::cvitemwithcomment_with_spacing(None, header, text, comment)
+}
+
+/// Make a resume entry with a proficiency comment and a spacing
+pub fn cvitemwithcomment_with_spacing<'a>(
+    spacing: Option<&str>,
+    header: impl Into<TexStr<'a>>,
+    text: impl Into<TexStr<'a>>,
+    comment: impl Into<TexStr<'a>>,
+) -> Element {
+    let (header, text, comment) = (header.into(), text.into(), comment.into());
+    let s = texify!("cvitemwithcomment", [spacing], header, text, comment);
+    Element::UserDefined(s)
+}
+
+/// Make a subsection heading (`\subsection`)
+pub fn subsection<'a>(title: impl Into<TexStr<'a>>) -> Element {
+    let title = title.into();
+    let s = texify!("subsection", title);
+    Element::UserDefined(s)
+}
+
+/// Break the page after the current line (`\pagebreak{}`)
+pub fn pagebreak() -> Element {
+    let empty = "";
+    let s = texify!("pagebreak", empty);
+    Element::UserDefined(s)
+}
+
+/// Start a new page (`\newpage{}`)
+pub fn newpage() -> Element {
+    let empty = "";
+    let s = texify!("newpage", empty);
+    Element::UserDefined(s)
+}
+
+/// Add vertical space, `length` is a LaTeX length like `-0.5em`
+pub fn vspace(length: &str) -> Element {
+    let s = texify!("vspace", length);
     Element::UserDefined(s)
 }
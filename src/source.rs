@@ -0,0 +1,287 @@
+//! Resume source files in YAML, TOML or JSON
+//!
+//! A source file is the serialized form of [`Resume`]. All fields except
+//! `personal.firstname` and `personal.lastname` are optional, unknown fields
+//! are rejected.
+//!
+//! Text fields are plain strings and get escaped, write `{ raw: "..." }` to
+//! pass LaTeX code through unchanged.
+//!
+//! With the `schema` feature, `json_schema()` describes the format for editors.
+//!
+//! ```yaml
+//! title: Design enthusiast
+//! theme:
+//!   style: casual          # banking, casual, classic, empty, fancy, oldstyle
+//!   color: green           # black, blue, burgundy, green, grey, orange, purple, red
+//!   color1: "#1A2B3C"      # overrides the main color, also rgb(0.1, 0.2, 0.3)
+//!   color2: rgb(0.5, 0.5, 0.5)
+//! personal:
+//!   firstname: John
+//!   lastname: Doe
+//!   photo:
+//!     file: picture.jpg
+//!     width: 64pt
+//!     frame: 0.4pt
+//!   quote: Any intelligent fool can make things bigger
+//!   extrainfo:
+//!     raw: \weblink{www.ctan.org}
+//! contact:
+//!   address:
+//!     street: 12 somestreet
+//!     city: 3456 somecity
+//!     country: Some country
+//!   phones:
+//!     - number: +123 456 7890
+//!       phone_type: mobile   # fixed, mobile, fax
+//!   email: jdoe@design.org
+//!   homepage: www.jdoe.org
+//!   socials:
+//!     - account: jdoe
+//!       social_type: github  # linkedin, xing, twitter, github, gitlab, stackoverflow, ...
+//!       url: https://github.com/jdoe
+//! metadata:                # PDF metadata, title and author default to the above
+//!   subject: Resume of John Doe
+//!   keywords: [Design, Rust]
+//! sections:
+//!   - title: Education
+//!     order: newest-first    # sorts dated entries, also oldest-first
+//!     entries:
+//!       - type: entry        # \cventry
+//!         years: 2020--2021  # also 2020-09, Sep 2020 -- present, or free text
+//!         job: Degree
+//!         employer: Institution
+//!         localization: City
+//!         grade: Grade
+//!         comment:             # a string, or paragraphs and bullet lists
+//!           - Description
+//!           - bullets:
+//!               - Highlight
+//!               - text: Highlight with details
+//!                 bullets: [Detail]
+//!       - type: language     # \cvlanguage
+//!         name: English
+//!         level: Fluent
+//!         comment: Comment
+//!       - type: computer     # \cvcomputer
+//!         category1: Languages
+//!         programs1: Rust, C
+//!         category2: Tools
+//!         programs2: git
+//!       - type: line         # \cvline
+//!         leftmark: title
+//!         text: Title
+//!       - type: item         # \cvitem
+//!         header: header
+//!         text: text
+//!       - type: doubleitem   # \cvdoubleitem
+//!         header1: header 1
+//!         text1: text 1
+//!         header2: header 2
+//!         text2: text 2
+//!       - type: listitem     # \cvlistitem
+//!         item: Item
+//!       - type: listdoubleitem # \cvlistdoubleitem
+//!         item1: Item 1
+//!         item2: Item 2
+//!       - type: itemwithcomment # \cvitemwithcomment
+//!         header: header
+//!         text: text
+//!         comment: comment
+//!       - type: subsection   # \subsection followed by its entries
+//!         title: Vocational
+//!         entries:
+//!           - type: listitem
+//!             item: Item
+//!       - type: vspace       # \vspace, also pagebreak and newpage
+//!         length: -0.5em
+//! ```
+
+use crate::resume::Resume;
+use std::path::{Path, PathBuf};
+
+/// Source file formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Guess the format from the file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Position in a source file, both one-based
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Errors of loading or saving source files
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to read or write the file
+    Io(std::io::Error),
+    /// The file extension is not one of the known formats
+    UnknownFormat(PathBuf),
+    /// The file is not a valid resume
+    Parse {
+        position: Option<Position>,
+        message: String,
+    },
+    /// The resume could not be written in the format
+    Serialize(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::UnknownFormat(path) => write!(f, "unknown resume format: {}", path.display()),
+            Self::Parse {
+                position: Some(position),
+                message,
+            } => write!(
+                f,
+                "line {}, column {}: {}",
+                position.line, position.column, message
+            ),
+            Self::Parse {
+                position: None,
+                message,
+            } => write!(f, "{}", message),
+            Self::Serialize(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_yaml::Error> for Error {
+    fn from(e: serde_yaml::Error) -> Self {
+        let position = e.location().map(|location| Position {
+            line: location.line(),
+            column: location.column(),
+        });
+
+        Self::Parse {
+            position,
+            message: strip_position(&e.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        let position = if e.line() > 0 {
+            Some(Position {
+                line: e.line(),
+                column: e.column(),
+            })
+        } else {
+            None
+        };
+
+        Self::Parse {
+            position,
+            message: strip_position(&e.to_string()),
+        }
+    }
+}
+
+/// serde_yaml and serde_json append the position to their messages
+fn strip_position(message: &str) -> String {
+    match message.rfind(" at line ") {
+        Some(i) => message[..i].to_string(),
+        None => message.to_string(),
+    }
+}
+
+fn toml_error(e: toml::de::Error, s: &str) -> Error {
+    let position = e.span().map(|span| {
+        let before = &s[..span.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    });
+
+    Error::Parse {
+        position,
+        message: e.message().to_string(),
+    }
+}
+
+/// Load a resume from a string
+pub fn from_str(s: &str, format: Format) -> Result<Resume, Error> {
+    let resume = match format {
+        Format::Yaml => serde_yaml::from_str(s)?,
+        Format::Toml => toml::from_str(s).map_err(|e| toml_error(e, s))?,
+        Format::Json => serde_json::from_str(s)?,
+    };
+
+    Ok(resume)
+}
+
+/// Load a resume from a file, the format is taken from the extension
+pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Resume, Error> {
+    let path = path.as_ref();
+    let format = Format::from_path(path).ok_or_else(|| Error::UnknownFormat(path.into()))?;
+    let s = std::fs::read_to_string(path)?;
+
+    from_str(&s, format)
+}
+
+/// Save a resume to a string
+pub fn to_string(resume: &Resume, format: Format) -> Result<String, Error> {
+    let s = match format {
+        Format::Yaml => {
+            serde_yaml::to_string(resume).map_err(|e| Error::Serialize(e.to_string()))?
+        }
+        Format::Toml => {
+            toml::to_string_pretty(resume).map_err(|e| Error::Serialize(e.to_string()))?
+        }
+        Format::Json => {
+            serde_json::to_string_pretty(resume).map_err(|e| Error::Serialize(e.to_string()))?
+        }
+    };
+
+    Ok(s)
+}
+
+/// JSON Schema of the source format
+///
+/// YAML editors pick it up from a `# yaml-language-server: $schema=<path>`
+/// comment at the top of the file.
+#[cfg(feature = "schema")]
+pub fn json_schema() -> String {
+    let schema = schemars::schema_for!(Resume);
+
+    serde_json::to_string_pretty(&schema).expect("JSON Schema is always serializable")
+}
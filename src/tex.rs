@@ -0,0 +1,297 @@
+use std::borrow::Cow;
+
+/// A string argument of a moderncv command
+///
+/// Plain text is escaped when rendered, while raw LaTeX is emitted unchanged.
+/// Any `&str` or `String` converts into plain text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TexStr<'a> {
+    /// Plain text, LaTeX special characters are escaped
+    Text(Cow<'a, str>),
+    /// Raw LaTeX, emitted as is
+    Raw(Cow<'a, str>),
+}
+
+impl<'a> TexStr<'a> {
+    /// Make a plain text argument
+    pub fn text<S: Into<Cow<'a, str>>>(s: S) -> Self {
+        Self::Text(s.into())
+    }
+
+    /// Make a raw LaTeX argument
+    pub fn raw<S: Into<Cow<'a, str>>>(s: S) -> Self {
+        Self::Raw(s.into())
+    }
+
+    /// Get the underlying string, without escaping
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Text(s) | Self::Raw(s) => s,
+        }
+    }
+
+    /// Make a raw LaTeX argument from inline Markdown
+    #[cfg(feature = "markdown")]
+    pub fn markdown(s: &str) -> TexStr<'static> {
+        TexStr::raw(crate::markdown::to_latex(s))
+    }
+
+    /// Convert into an owned argument
+    pub fn into_owned(self) -> TexStr<'static> {
+        match self {
+            Self::Text(s) => TexStr::Text(Cow::Owned(s.into_owned())),
+            Self::Raw(s) => TexStr::Raw(Cow::Owned(s.into_owned())),
+        }
+    }
+}
+
+impl Default for TexStr<'_> {
+    fn default() -> Self {
+        Self::text("")
+    }
+}
+
+impl<'a> From<&'a str> for TexStr<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::text(s)
+    }
+}
+
+impl<'a> From<&'a String> for TexStr<'a> {
+    fn from(s: &'a String) -> Self {
+        Self::text(s.as_str())
+    }
+}
+
+impl From<String> for TexStr<'static> {
+    fn from(s: String) -> Self {
+        Self::text(s)
+    }
+}
+
+impl<'a> From<&'a TexStr<'_>> for TexStr<'a> {
+    fn from(s: &'a TexStr<'_>) -> Self {
+        match s {
+            TexStr::Text(s) => Self::text(s.as_ref()),
+            TexStr::Raw(s) => Self::raw(s.as_ref()),
+        }
+    }
+}
+
+impl std::fmt::Display for TexStr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(s) => write!(f, "{}", escape(s)),
+            Self::Raw(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+const SPECIAL_CHARS: &[char] = &['&', '%', '$', '#', '_', '{', '}', '~', '^', '\\'];
+
+/// Escape LaTeX special characters (`& % $ # _ { } ~ ^ \`)
+pub fn escape(s: &str) -> Cow<'_, str> {
+    if !s.contains(SPECIAL_CHARS) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut escaped = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str(r"\textasciitilde{}"),
+            '^' => escaped.push_str(r"\textasciicircum{}"),
+            '\\' => escaped.push_str(r"\textbackslash{}"),
+            _ => escaped.push(c),
+        }
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Plain text is (de)serialized as a string, raw LaTeX as `{ "raw": "..." }`
+///
+/// Numbers and booleans are read as text.
+#[cfg(feature = "serde")]
+impl serde::Serialize for TexStr<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        match self {
+            Self::Text(s) => serializer.serialize_str(s),
+            Self::Raw(s) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("raw", s)?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// Visitor methods reading strings, numbers and booleans as plain text
+///
+/// The text is passed to `$wrap`, e.g. `Bullet::new`.
+#[cfg(feature = "serde")]
+macro_rules! visit_text {
+    ($wrap:expr) => {
+        fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+            Ok($wrap($crate::tex::TexStr::text(s.to_string())))
+        }
+
+        fn visit_string<E: serde::de::Error>(self, s: String) -> Result<Self::Value, E> {
+            Ok($wrap($crate::tex::TexStr::text(s)))
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok($wrap($crate::tex::TexStr::text(v.to_string())))
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok($wrap($crate::tex::TexStr::text(v.to_string())))
+        }
+
+        fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            Ok($wrap($crate::tex::TexStr::text(v.to_string())))
+        }
+
+        fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Self::Value, E> {
+            Ok($wrap($crate::tex::TexStr::text(v.to_string())))
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use visit_text;
+
+/// Reads text from a scalar, raw LaTeX from a map with a single `raw` key
+#[cfg(feature = "serde")]
+pub(crate) struct TexStrVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for TexStrVisitor {
+    type Value = TexStr<'static>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "text, or raw LaTeX as `{{ raw: ... }}`")
+    }
+
+    visit_text!(std::convert::identity);
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        use serde::de::Error;
+
+        let raw = match map.next_key::<String>()? {
+            Some(key) if key == "raw" => map.next_value::<String>()?,
+            Some(key) => return Err(A::Error::unknown_field(&key, &["raw"])),
+            None => return Err(A::Error::missing_field("raw")),
+        };
+
+        if let Some(key) = map.next_key::<String>()? {
+            return Err(A::Error::unknown_field(&key, &["raw"]));
+        }
+
+        Ok(TexStr::raw(raw))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TexStr<'static> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TexStrVisitor)
+    }
+}
+
+/// Reverse `escape`
+pub fn unescape(s: &str) -> Cow<'_, str> {
+    if !s.contains('\\') {
+        return Cow::Borrowed(s);
+    }
+
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('\\') {
+        unescaped.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        let replacements = [
+            (r"\textasciitilde{}", "~"),
+            (r"\textasciicircum{}", "^"),
+            (r"\textbackslash{}", "\\"),
+        ];
+        if let Some((from, to)) = replacements.iter().find(|(from, _)| rest.starts_with(from)) {
+            unescaped.push_str(to);
+            rest = &rest[from.len()..];
+            continue;
+        }
+
+        match rest[1..].chars().next() {
+            Some(c) if SPECIAL_CHARS.contains(&c) && c != '\\' => {
+                unescaped.push(c);
+                rest = &rest[1 + c.len_utf8()..];
+            }
+            _ => {
+                unescaped.push('\\');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+
+    Cow::Owned(unescaped)
+}
+
+/// Either a string or a map with a single `raw` key
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for TexStr<'_> {
+    fn schema_name() -> String {
+        "TexStr".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{
+            InstanceType, Metadata, ObjectValidation, Schema, SchemaObject, SubschemaValidation,
+        };
+
+        let text = SchemaObject {
+            instance_type: Some(
+                vec![
+                    InstanceType::String,
+                    InstanceType::Number,
+                    InstanceType::Boolean,
+                ]
+                .into(),
+            ),
+            ..SchemaObject::default()
+        };
+
+        let raw = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(ObjectValidation {
+                properties: vec![("raw".to_string(), gen.subschema_for::<String>())]
+                    .into_iter()
+                    .collect(),
+                required: vec!["raw".to_string()].into_iter().collect(),
+                additional_properties: Some(Box::new(Schema::Bool(false))),
+                ..ObjectValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+
+        let schema = SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some("Plain text, or raw LaTeX as `{ raw: ... }`".to_string()),
+                ..Metadata::default()
+            })),
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![text.into(), raw.into()]),
+                ..SubschemaValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+
+        schema.into()
+    }
+}
@@ -0,0 +1,351 @@
+//! Unicode handling for the target engine
+//!
+//! xelatex and lualatex read UTF-8 natively, so text is passed through.
+//! For pdflatex, known characters are replaced by LaTeX macros (`ü` becomes
+//! `\"{u}`), Chinese, Japanese and Korean text is set with `CJKutf8` and the
+//! other characters are reported.
+//!
+//! Helpers emit UTF-8, a rendered document is encoded once with
+//! [`encode_document`]. `Resume::render()` does it with the engine and the
+//! policy of its `RenderOptions`:
+//!
+//! ```
+//! use moderncv::{RenderOptions, Resume};
+//!
+//! let mut resume = Resume::new();
+//! resume.personal.firstname = "Jürgen".into();
+//!
+//! let rendered = resume.render(&RenderOptions::new());
+//! for unrepresentable in &rendered.unrepresentable {
+//!     eprintln!("warning: {}", unrepresentable);
+//! }
+//! ```
+
+use crate::Engine;
+use latex::{Document, Element, PreambleElement, Section};
+use std::borrow::Cow;
+
+/// How non-ASCII characters are rendered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnicodePolicy {
+    /// Pass through for xelatex and lualatex, transliterate for pdflatex
+    #[default]
+    Auto,
+    /// Emit characters unchanged
+    PassThrough,
+    /// Replace known characters by LaTeX macros and report the others
+    Transliterate,
+}
+
+impl UnicodePolicy {
+    /// Whether text is transliterated for `engine`
+    pub fn transliterates(self, engine: Engine) -> bool {
+        match self {
+            Self::Auto => !engine.is_unicode(),
+            Self::PassThrough => false,
+            Self::Transliterate => true,
+        }
+    }
+}
+
+/// A character which has no LaTeX macro, it is emitted unchanged
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Unrepresentable {
+    pub character: char,
+    /// The string containing the character
+    pub text: String,
+}
+
+impl std::fmt::Display for Unrepresentable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "character `{}` (U+{:04X}) cannot be represented with pdflatex in `{}`",
+            self.character, self.character as u32, self.text
+        )
+    }
+}
+
+/// Encode the strings of a document for an engine
+///
+/// Collects the unrepresentable characters and whether `CJKutf8` is needed.
+#[derive(Clone, Debug)]
+pub struct Encoder {
+    transliterate: bool,
+    cjk: bool,
+    unrepresentable: Vec<Unrepresentable>,
+}
+
+impl Encoder {
+    /// Create an encoder for `engine` with `policy`
+    pub fn new(engine: Engine, policy: UnicodePolicy) -> Self {
+        Self {
+            transliterate: policy.transliterates(engine),
+            cjk: false,
+            unrepresentable: Vec::new(),
+        }
+    }
+
+    /// Encode `s`, CJK runs are wrapped in a `CJK` environment
+    pub fn encode(&mut self, s: &str) -> String {
+        self.encode_with(s, true)
+    }
+
+    /// Encode `s` for a PDF string such as the `\hypersetup` metadata
+    ///
+    /// CJK runs are kept as plain text, hyperref converts them itself.
+    pub fn encode_pdf_string(&mut self, s: &str) -> String {
+        self.encode_with(s, false)
+    }
+
+    fn encode_with(&mut self, s: &str, cjk_environment: bool) -> String {
+        if !self.transliterate || s.is_ascii() {
+            return s.to_string();
+        }
+
+        let mut encoded = String::with_capacity(s.len() + 8);
+        let mut rest = s;
+        while let Some(start) = rest.find(is_cjk) {
+            let end = rest[start..]
+                .find(|c| !is_cjk(c))
+                .map_or(rest.len(), |end| start + end);
+            self.transliterate_into(&mut encoded, &rest[..start], s);
+
+            let run = &rest[start..end];
+            if cjk_environment {
+                encoded.push_str(&format!(
+                    r"\begin{{CJK}}{{UTF8}}{{{}}}{}\end{{CJK}}",
+                    cjk_font(run),
+                    run
+                ));
+                self.cjk = true;
+            } else {
+                encoded.push_str(run);
+            }
+            rest = &rest[end..];
+        }
+        self.transliterate_into(&mut encoded, rest, s);
+
+        encoded
+    }
+
+    /// Whether an encoded string contains CJK text
+    pub fn uses_cjk(&self) -> bool {
+        self.cjk
+    }
+
+    /// The characters reported so far
+    pub fn unrepresentable(&self) -> &[Unrepresentable] {
+        &self.unrepresentable
+    }
+
+    /// Take the characters reported so far
+    pub fn finish(self) -> Vec<Unrepresentable> {
+        self.unrepresentable
+    }
+
+    fn transliterate_into(&mut self, encoded: &mut String, s: &str, text: &str) {
+        let (transliterated, unrepresentable) = transliterate_str(s);
+        encoded.push_str(&transliterated);
+        self.unrepresentable.extend(
+            unrepresentable
+                .into_iter()
+                .map(|character| Unrepresentable {
+                    character,
+                    text: text.to_string(),
+                }),
+        );
+    }
+
+    fn encode_element(&mut self, elem: &Element) -> Element {
+        match elem {
+            Element::UserDefined(s) => Element::UserDefined(self.encode(s)),
+            Element::Environment(name, lines) => Element::Environment(
+                name.clone(),
+                lines.iter().map(|line| self.encode(line)).collect(),
+            ),
+            Element::Section(section) => {
+                let mut encoded = Section::new(&self.encode(&section.name));
+                for elem in section.iter() {
+                    encoded.push(self.encode_element(elem));
+                }
+                Element::Section(encoded)
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+/// Encode the preamble and the elements of `doc` for `engine`
+///
+/// `\usepackage{CJKutf8}` is added if CJK text was found. Paragraphs, lists
+/// and alignments are kept as they are, so are file names like the photo's.
+/// The `\hypersetup` metadata is encoded as a PDF string.
+pub fn encode_document(
+    doc: &Document,
+    engine: Engine,
+    policy: UnicodePolicy,
+) -> (Document, Vec<Unrepresentable>) {
+    let mut encoder = Encoder::new(engine, policy);
+    let mut encoded = Document::new(doc.class.clone());
+
+    encoded.preamble.title = doc.preamble.title.as_deref().map(|t| encoder.encode(t));
+    encoded.preamble.author = doc.preamble.author.as_deref().map(|a| encoder.encode(a));
+    for elem in doc.preamble.iter() {
+        encoded.preamble.push(match elem {
+            PreambleElement::UserDefined(s) if is_file_command(s) => elem.clone(),
+            PreambleElement::UserDefined(s) if s.contains(r"\hypersetup") => {
+                PreambleElement::UserDefined(encoder.encode_pdf_string(s))
+            }
+            PreambleElement::UserDefined(s) => PreambleElement::UserDefined(encoder.encode(s)),
+            other => other.clone(),
+        });
+    }
+
+    for elem in doc.iter() {
+        encoded.push(encoder.encode_element(elem));
+    }
+
+    if encoder.uses_cjk() {
+        encoded.preamble.use_package("CJKutf8");
+    }
+
+    (encoded, encoder.finish())
+}
+
+/// Whether `s` is a command taking a file name, like `\photo`
+fn is_file_command(s: &str) -> bool {
+    [r"\photo", r"\includegraphics", r"\input"].iter().any(
+        |command| matches!(s.strip_prefix(command), Some(rest) if rest.starts_with(['[', '{'])),
+    )
+}
+
+/// Replace known non-ASCII characters by LaTeX macros
+///
+/// Returns the characters which have no macro, they are left unchanged.
+pub fn transliterate_str(s: &str) -> (Cow<'_, str>, Vec<char>) {
+    if s.is_ascii() {
+        return (Cow::Borrowed(s), Vec::new());
+    }
+
+    let mut encoded = String::with_capacity(s.len() + 8);
+    let mut unrepresentable = Vec::new();
+    for c in s.chars() {
+        if c.is_ascii() {
+            encoded.push(c);
+        } else if let Some((accent, base)) = accented(c) {
+            encoded.push_str(&format!(r"\{}{{{}}}", accent, base));
+        } else if let Some(macro_) = symbol(c) {
+            encoded.push_str(macro_);
+        } else {
+            encoded.push(c);
+            unrepresentable.push(c);
+        }
+    }
+
+    (Cow::Owned(encoded), unrepresentable)
+}
+
+/// Accent command and base letter of an accented Latin letter
+fn accented(c: char) -> Option<(char, char)> {
+    const ACCENTS: &[(char, &str, &str)] = &[
+        ('`', "ÀÈÌÒÙàèìòù", "AEIOUaeiou"),
+        ('\'', "ÁÉÍÓÚÝáéíóúýĆćĹĺŃńŔŕŚśŹź", "AEIOUYaeiouyCcLlNnRrSsZz"),
+        ('^', "ÂÊÎÔÛâêîôûĈĉĜĝĤĥĴĵŜŝŴŵŶŷ", "AEIOUaeiouCcGgHhJjSsWwYy"),
+        ('~', "ÃÑÕãñõĨĩŨũ", "ANOanoIiUu"),
+        ('"', "ÄËÏÖÜäëïöüÿŸ", "AEIOUaeiouyY"),
+        ('v', "ČčĎďĚěĽľŇňŘřŠšŤťŽž", "CcDdEeLlNnRrSsTtZz"),
+        ('c', "ÇçĢģĶķĻļŅņŖŗŞşŢţ", "CcGgKkLlNnRrSsTt"),
+        ('k', "ĄąĘęĮįŲų", "AaEeIiUu"),
+        ('.', "ĊċĖėĠġİŻż", "CcEeGgIZz"),
+        ('H', "ŐőŰű", "OoUu"),
+        ('u', "ĂăĔĕĞğĬĭŎŏŬŭ", "AaEeGgIiOoUu"),
+        ('=', "ĀāĒēĪīŌōŪū", "AaEeIiOoUu"),
+        ('r', "ÅåŮů", "AaUu"),
+    ];
+
+    ACCENTS.iter().find_map(|(accent, accented, bases)| {
+        let i = accented.chars().position(|a| a == c)?;
+        bases.chars().nth(i).map(|base| (*accent, base))
+    })
+}
+
+/// Whether `c` is set with `CJKutf8`
+fn is_cjk(c: char) -> bool {
+    match c {
+        '\u{3000}'..='\u{303F}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' => true,
+        '\u{F900}'..='\u{FAFF}' | '\u{FF00}'..='\u{FFEF}' => true,
+        _ => is_kana(c) || is_hangul(c),
+    }
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30FF}')
+}
+
+fn is_hangul(c: char) -> bool {
+    let jamo = matches!(c, '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}');
+
+    jamo || matches!(c, '\u{AC00}'..='\u{D7AF}')
+}
+
+/// `CJKutf8` font family of a run: Japanese with kana, Korean with Hangul,
+/// simplified Chinese otherwise
+fn cjk_font(run: &str) -> &'static str {
+    if run.chars().any(is_kana) {
+        "min"
+    } else if run.chars().any(is_hangul) {
+        "mj"
+    } else {
+        "gbsn"
+    }
+}
+
+/// LaTeX macro of a letter or symbol without a base letter
+fn symbol(c: char) -> Option<&'static str> {
+    let s = match c {
+        'ß' => r"\ss{}",
+        'Æ' => r"\AE{}",
+        'æ' => r"\ae{}",
+        'Ø' => r"\O{}",
+        'ø' => r"\o{}",
+        'Œ' => r"\OE{}",
+        'œ' => r"\oe{}",
+        'Ł' => r"\L{}",
+        'ł' => r"\l{}",
+        'Đ' => r"\DJ{}",
+        'đ' => r"\dj{}",
+        'Ð' => r"\DH{}",
+        'ð' => r"\dh{}",
+        'Þ' => r"\TH{}",
+        'þ' => r"\th{}",
+        'ı' => r"\i{}",
+        '\u{a0}' => "~",
+        '–' => "--",
+        '—' => "---",
+        '‘' => "`",
+        '’' => "'",
+        '“' => "``",
+        '”' => "''",
+        '„' => r"\quotedblbase{}",
+        '«' => r"\guillemotleft{}",
+        '»' => r"\guillemotright{}",
+        '…' => r"\ldots{}",
+        '•' => r"\textbullet{}",
+        '·' => r"\textperiodcentered{}",
+        '°' => r"\textdegree{}",
+        '©' => r"\textcopyright{}",
+        '®' => r"\textregistered{}",
+        '™' => r"\texttrademark{}",
+        '€' => r"\texteuro{}",
+        '£' => r"\pounds{}",
+        '§' => r"\S{}",
+        '¶' => r"\P{}",
+        '¿' => r"?`",
+        '¡' => r"!`",
+        '×' => r"\texttimes{}",
+        _ => return None,
+    };
+
+    Some(s)
+}
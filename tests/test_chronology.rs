@@ -0,0 +1,136 @@
+use latex::Element;
+use moderncv::chronology::{lint, Issue, Order, Span};
+use moderncv::resume::{CVEntry, ResumeSection};
+
+fn entry(years: &str, job: &str) -> CVEntry {
+    CVEntry::Entry {
+        years: years.into(),
+        job: job.to_string().into(),
+        employer: "Company".into(),
+        localization: "".into(),
+        grade: None,
+        comment: None,
+    }
+}
+
+fn span(label: &str, range: &str) -> Span {
+    Span {
+        label: label.to_string(),
+        range: range.parse().unwrap(),
+    }
+}
+
+fn jobs(section: &ResumeSection) -> Vec<String> {
+    section
+        .entries
+        .iter()
+        .map(|entry| match entry {
+            CVEntry::Entry { job, .. } => job.as_str().to_string(),
+            CVEntry::Subsection { title, .. } => title.clone(),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_sort_resume_section() {
+    let mut section = ResumeSection::new("Experience");
+    section
+        .push(entry("2015--2017", "A"))
+        .push(entry("2019--present", "B"))
+        .push(CVEntry::Item {
+            header: "Note".into(),
+            text: "Undated".into(),
+        })
+        .push(entry("2017-06--2019-01", "C"))
+        .push(entry("year--year", "D"));
+
+    section.sort_chronologically(Order::NewestFirst);
+    assert_eq!(jobs(&section), ["B", "C", "", "A", "D"]);
+
+    section.sort_chronologically(Order::OldestFirst);
+    assert_eq!(jobs(&section), ["A", "C", "", "B", "D"]);
+}
+
+#[test]
+fn test_sort_subsection() {
+    let mut section = ResumeSection::new("Experience");
+    section.push(CVEntry::Subsection {
+        title: "Vocational".to_string(),
+        entries: vec![entry("2010--2012", "A"), entry("2014--2016", "B")],
+    });
+    section.push(entry("2012--2014", "C"));
+
+    section.sort_chronologically(Order::NewestFirst);
+    assert_eq!(jobs(&section), ["Vocational", "C"]);
+    match &section.entries[0] {
+        CVEntry::Subsection { entries, .. } => {
+            assert_eq!(entries[0], entry("2014--2016", "B"));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_to_section_order() {
+    let mut section = ResumeSection::new("Education");
+    section
+        .push(entry("2010--2013", "Bachelor"))
+        .push(entry("2013--2015", "Master"));
+    section.order = Some(Order::NewestFirst);
+
+    let left = section.to_section().iter().cloned().collect::<Vec<_>>();
+    let right = vec![
+        Element::UserDefined(r"\cventry{2013--2015}{Master}{Company}{}{}{}".to_string()),
+        Element::UserDefined(r"\cventry{2010--2013}{Bachelor}{Company}{}{}{}".to_string()),
+    ];
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_lint() {
+    let spans = [
+        span("A", "2019-06--present"),
+        span("B", "2015--2017"),
+        span("C", "2017--2018-12"),
+        span("D", "2019-03--2019-09"),
+    ];
+
+    let issues = lint(&spans, 3);
+    assert_eq!(
+        issues,
+        [Issue::Overlap {
+            first: spans[3].clone(),
+            second: spans[0].clone(),
+        }]
+    );
+
+    let issues = lint(&spans, 1);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(
+        issues[0].to_string(),
+        "gap of 2 months between `C` (2017--2018-12) and `D` (2019-03--2019-09)"
+    );
+    assert_eq!(
+        issues[1].to_string(),
+        "`A` (2019-06--present) overlaps `D` (2019-03--2019-09)"
+    );
+
+    assert!(lint(&[span("A", "2019--2020"), span("B", "2020--2021")], 0).is_empty());
+}
+
+#[test]
+fn test_lint_sections() {
+    let mut section = ResumeSection::new("Experience");
+    section
+        .push(entry("2010--2012", "A"))
+        .push(CVEntry::Subsection {
+            title: "Miscellaneous".to_string(),
+            entries: vec![entry("2014--2016", "B")],
+        });
+    let issues = section.lint(6);
+    assert_eq!(
+        issues.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        ["gap of 12 months between `A, Company` (2010--2012) and `B, Company` (2014--2016)"]
+    );
+}
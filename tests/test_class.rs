@@ -0,0 +1,81 @@
+use latex::{Document, DocumentClass, Section};
+use moderncv::class::*;
+use moderncv::document_class;
+
+#[test]
+fn test_to_documentclass() {
+    let left = ClassOptions::new().to_documentclass();
+    assert_eq!(left, Ok(r"\documentclass{moderncv}".to_string()));
+
+    let left = ClassOptions::new()
+        .font_size(FontSize::Pt11)
+        .paper_size(PaperSize::A4)
+        .font_family(FontFamily::Sans)
+        .draft()
+        .colorlinks()
+        .to_documentclass();
+    assert_eq!(
+        left,
+        Ok(r"\documentclass[11pt,a4paper,sans,draft,colorlinks]{moderncv}".to_string())
+    );
+}
+
+#[test]
+fn test_conflicts() {
+    let left = ClassOptions::new()
+        .font_size(FontSize::Pt11)
+        .paper_size(PaperSize::Letter)
+        .font_size(FontSize::Pt12)
+        .to_documentclass();
+    assert_eq!(
+        left,
+        Err(Error::Conflict(
+            ClassOption::FontSize(FontSize::Pt11),
+            ClassOption::FontSize(FontSize::Pt12)
+        ))
+    );
+
+    let left = ClassOptions::new()
+        .font_family(FontFamily::Sans)
+        .font_family(FontFamily::Roman)
+        .validate();
+    assert!(left.is_err());
+
+    let left = ClassOptions::new().draft().final_().validate();
+    assert_eq!(
+        left.unwrap_err().to_string(),
+        "class option `draft` conflicts with `final`"
+    );
+
+    let left = ClassOptions::new().landscape().landscape().validate();
+    assert!(left.is_err());
+}
+
+#[test]
+fn test_print() {
+    let mut doc = Document::new(document_class());
+    doc.preamble.title("Resume");
+    doc.preamble.use_package("fontspec");
+    doc.push(Section::new("Education"));
+
+    let left = ClassOptions::new()
+        .font_size(FontSize::Pt11)
+        .paper_size(PaperSize::A4)
+        .print(&doc)
+        .unwrap();
+    let right = latex::print(&doc).unwrap().replace(
+        r"\documentclass{moderncv}",
+        r"\documentclass[11pt,a4paper]{moderncv}",
+    );
+
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_print_part() {
+    let mut doc = Document::new(DocumentClass::Part);
+    doc.push(Section::new("Education"));
+
+    let left = ClassOptions::new().final_().print(&doc).unwrap();
+    assert_eq!(left, latex::print(&doc).unwrap());
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "compile")]
+
+use moderncv::chronology::Order;
+use moderncv::compile::{parse_log, Compiler, DiagnosticKind, Engine, Error, Severity, SourceMap};
+use moderncv::date::Years;
+use moderncv::resume::{CVEntry, Photo, Resume, ResumeSection};
+use moderncv::RenderOptions;
+
+const LOG: &str = r"This is pdfTeX, Version 3.141592653-2.6-1.40.25 (TeX Live 2023) (preloaded format=pdflatex)
+entering extended mode
+(./resume.tex
+LaTeX2e <2022-11-01> patch level 1
+! Undefined control sequence.
+l.14 \cvitme
+            {header}{text}
+Here is how much of TeX's memory you used:
+Overfull \hbox (12.34pt too wide) in paragraph at lines 20--21
+! Package pdftex.def Error: File `picture.jpg' not found: using draft setting.
+
+See the pdftex.def package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.8 \photo[64pt]{picture.jpg}
+
+! LaTeX Error: File `moderncv.cls' not found.
+";
+
+#[test]
+fn test_parse_log() {
+    let diagnostics = parse_log(LOG);
+    assert_eq!(diagnostics.len(), 4);
+
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(
+        diagnostics[0].kind,
+        DiagnosticKind::UndefinedControlSequence(r"\cvitme".to_string())
+    );
+    assert_eq!(diagnostics[0].line, Some(14));
+
+    assert_eq!(diagnostics[1].severity, Severity::Warning);
+    assert_eq!(diagnostics[1].kind, DiagnosticKind::OverfullBox);
+    assert_eq!(diagnostics[1].line, Some(20));
+
+    assert_eq!(
+        diagnostics[2].kind,
+        DiagnosticKind::MissingFile("picture.jpg".to_string())
+    );
+    assert_eq!(diagnostics[2].line, Some(8));
+
+    assert_eq!(diagnostics[3].kind, DiagnosticKind::MissingClass);
+    assert_eq!(diagnostics[3].line, None);
+}
+
+#[test]
+fn test_parse_log_xelatex() {
+    let log = "! Unable to load picture or PDF file 'picture.jpg'.\n<to be read again>\n}\nl.8 \\photo[64pt]{picture.jpg}\n";
+    let diagnostics = parse_log(log);
+
+    assert_eq!(
+        diagnostics,
+        vec![moderncv::compile::Diagnostic {
+            severity: Severity::Error,
+            kind: DiagnosticKind::MissingFile("picture.jpg".to_string()),
+            message: "Unable to load picture or PDF file 'picture.jpg'.".to_string(),
+            line: Some(8),
+            entry: None,
+        }]
+    );
+}
+
+#[test]
+fn test_source_map() {
+    let mut education = ResumeSection::new("Education");
+    education
+        .push(CVEntry::Item {
+            header: "Degree".into(),
+            text: "Institution".into(),
+        })
+        .push(CVEntry::ListItem {
+            item: "Thesis".into(),
+        });
+
+    let mut languages = ResumeSection::new("Languages");
+    languages.push(CVEntry::Item {
+        header: "Degree".into(),
+        text: "Institution".into(),
+    });
+
+    let mut resume = Resume::new();
+    resume.personal.firstname = "John".into();
+    resume.personal.lastname = "Doe".into();
+    resume.push(education).push(languages);
+
+    let source = latex::print(&resume.to_document()).unwrap();
+    let map = SourceMap::new(&source, &resume, &RenderOptions::default());
+
+    let line_of = |needle: &str, skip: usize| {
+        source
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.contains(needle))
+            .nth(skip)
+            .map(|(i, _)| i + 1)
+            .unwrap()
+    };
+
+    let entry = map.entry(line_of(r"\cvlistitem", 0)).unwrap();
+    assert_eq!((entry.section, entry.entry), (0, 1));
+    assert_eq!(entry.title, "Education");
+
+    // Identical entries are told apart by their order
+    let entry = map.entry(line_of(r"\cvitem", 1)).unwrap();
+    assert_eq!((entry.section, entry.entry), (1, 0));
+    assert_eq!(entry.title, "Languages");
+
+    assert_eq!(map.entry(1), None);
+
+    // Sorted sections map to the entries in `entries`
+    let mut experience = ResumeSection::new("Experience");
+    experience.order = Some(Order::NewestFirst);
+    for (years, job) in [("2015--2017", "Intern"), ("2019--2021", "Engineer")] {
+        experience.push(CVEntry::Entry {
+            years: Years::Range(years.parse().unwrap()),
+            job: job.into(),
+            employer: "Company".into(),
+            localization: "City".into(),
+            grade: None,
+            comment: None,
+        });
+    }
+    resume.push(experience);
+
+    let source = latex::print(&resume.to_document()).unwrap();
+    let map = SourceMap::new(&source, &resume, &RenderOptions::default());
+    let line_of = |needle: &str| {
+        source
+            .lines()
+            .position(|line| line.contains(needle))
+            .unwrap()
+            + 1
+    };
+    assert!(line_of("Engineer") < line_of("Intern"));
+
+    let entry = map.entry(line_of("Engineer")).unwrap();
+    assert_eq!((entry.section, entry.entry), (2, 1));
+    let entry = map.entry(line_of("Intern")).unwrap();
+    assert_eq!((entry.section, entry.entry), (2, 0));
+}
+
+#[test]
+fn test_photo_outside_base_dir() {
+    let mut resume = Resume::new();
+    resume.personal.photo = Some(Photo {
+        file: "../../../home/user/.bashrc".to_string(),
+        ..Default::default()
+    });
+    let source = latex::print(&resume.to_document()).unwrap();
+
+    let options = RenderOptions::default();
+    let result =
+        Compiler::new(Engine::Pdflatex).compile_resume(&resume, &options, &source, "examples");
+    match result {
+        Err(Error::InvalidAsset(path)) => {
+            assert_eq!(path.to_str(), Some("../../../home/user/.bashrc"))
+        }
+        _ => panic!("the photo must be rejected"),
+    }
+}
+
+#[test]
+fn test_missing_photo() {
+    let mut resume = Resume::new();
+    resume.personal.photo = Some(Photo {
+        file: "missing.jpg".to_string(),
+        ..Default::default()
+    });
+    let source = latex::print(&resume.to_document()).unwrap();
+
+    let options = RenderOptions::default();
+    let result =
+        Compiler::new(Engine::Pdflatex).compile_resume(&resume, &options, &source, "examples");
+    match result {
+        Err(Error::MissingAsset(path)) => {
+            assert_eq!(path, std::path::Path::new("examples/missing.jpg"))
+        }
+        _ => panic!("the missing photo must be reported"),
+    }
+}
+
+#[test]
+fn test_photo_extension() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("picture.png"), b"").unwrap();
+
+    let mut resume = Resume::new();
+    resume.personal.photo = Some(Photo {
+        file: "picture".to_string(),
+        ..Default::default()
+    });
+    let source = latex::print(&resume.to_document()).unwrap();
+
+    // Whether the engine is installed or not, the photo is found
+    let options = RenderOptions::default();
+    let result =
+        Compiler::new(Engine::Pdflatex).compile_resume(&resume, &options, &source, dir.path());
+    assert!(!matches!(result, Err(Error::MissingAsset(_))));
+
+    resume.personal.photo.as_mut().unwrap().file = "other".to_string();
+    let result =
+        Compiler::new(Engine::Pdflatex).compile_resume(&resume, &options, &source, dir.path());
+    match result {
+        Err(Error::MissingAsset(path)) => assert_eq!(path, dir.path().join("other")),
+        _ => panic!("the missing photo must be reported"),
+    }
+}
@@ -0,0 +1,200 @@
+use latex::Element;
+use moderncv::date::{Date, DateError, DateFormat, DateRange, Language, Years};
+use moderncv::resume::{CVEntry, ResumeSection};
+use moderncv::section::cventry;
+use moderncv::{RenderOptions, Resume, TexStr};
+
+#[test]
+fn test_parse_date() {
+    assert_eq!("2019".parse(), Ok(Date::new(2019)));
+    assert_eq!("2019-03".parse(), Date::with_month(2019, 3));
+    assert_eq!("2019-03-15".parse(), Date::with_day(2019, 3, 15));
+    assert_eq!("Mar 2019".parse(), Date::with_month(2019, 3));
+    assert_eq!("März 2019".parse(), Date::with_month(2019, 3));
+    assert_eq!("15 janv. 2019".parse(), Date::with_day(2019, 1, 15));
+    assert_eq!("29 February 2020".parse(), Date::with_day(2020, 2, 29));
+
+    for s in ["19", "2019-13", "2019-02-29", "Foo 2019", "2019/03"] {
+        assert_eq!(
+            s.parse::<Date>(),
+            Err(DateError::Invalid(s.to_string())),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_parse_range() {
+    let range = DateRange::new(Date::new(2019)).until(Date::new(2021));
+    assert_eq!("2019--2021".parse(), Ok(range));
+
+    let range = DateRange::new(Date::with_month(2019, 1).unwrap()).present();
+    assert_eq!("Jan 2019 -- present".parse(), Ok(range));
+    assert_eq!("2019-01 – heute".parse(), Ok(range));
+    assert!(range.is_ongoing());
+
+    let range = DateRange::new(Date::with_month(2019, 1).unwrap())
+        .until(Date::with_month(2021, 6).unwrap());
+    assert_eq!("2019-01 – 2021-06".parse(), Ok(range));
+    assert_eq!(range.to_string(), "2019-01--2021-06");
+
+    assert_eq!("2019".parse(), Ok(DateRange::new(Date::new(2019))));
+    assert_eq!(
+        "2021--2019".parse::<DateRange>(),
+        Err(DateError::Reversed("2021--2019".to_string()))
+    );
+    assert!("2019--2019-06".parse::<DateRange>().is_ok());
+}
+
+#[test]
+fn test_format() {
+    let start = Date::with_month(2019, 3).unwrap();
+    let range = DateRange::new(start).present();
+    let day = Date::with_day(2019, 3, 5).unwrap();
+
+    let cases = [
+        (
+            DateFormat::Iso,
+            Language::English,
+            "2019-03--present",
+            "2019-03-05",
+        ),
+        (
+            DateFormat::Numeric,
+            Language::English,
+            "03/2019--present",
+            "05/03/2019",
+        ),
+        (
+            DateFormat::Numeric,
+            Language::German,
+            "03.2019--heute",
+            "05.03.2019",
+        ),
+        (
+            DateFormat::Short,
+            Language::English,
+            "Mar 2019--present",
+            "5 Mar 2019",
+        ),
+        (
+            DateFormat::Long,
+            Language::German,
+            "März 2019--heute",
+            "5. März 2019",
+        ),
+        (
+            DateFormat::Long,
+            Language::French,
+            "mars 2019--aujourd'hui",
+            "5 mars 2019",
+        ),
+    ];
+    for (format, language, left, right) in cases {
+        assert_eq!(range.format(format, language), left);
+        assert_eq!(day.format(format, language), right);
+    }
+
+    assert_eq!(
+        Date::new(2019).format(DateFormat::Long, Language::French),
+        "2019"
+    );
+}
+
+#[test]
+fn test_cventry() {
+    let range = DateRange::new(Date::with_month(2019, 3).unwrap()).present();
+
+    let years = range.to_tex(DateFormat::Short, Language::French);
+    let left = cventry(years, "Engineer", "Company", "City", None, None);
+    let right = Element::UserDefined(
+        r"\cventry{mars 2019--aujourd'hui}{Engineer}{Company}{City}{}{}".to_string(),
+    );
+    assert_eq!(left, right);
+
+    let left = cventry(range, "Engineer", "Company", "City", None, None);
+    let right = Element::UserDefined(
+        r"\cventry{2019-03--present}{Engineer}{Company}{City}{}{}".to_string(),
+    );
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_render_options() {
+    let entry = |years: Years| CVEntry::Entry {
+        years,
+        job: "Engineer".into(),
+        employer: "Company".into(),
+        localization: "City".into(),
+        grade: None,
+        comment: None,
+    };
+    let range = DateRange::new(Date::with_month(2019, 3).unwrap()).present();
+    let mut section = ResumeSection::new("Experience");
+    section
+        .push(entry(range.into()))
+        .push(entry("Mar 2019 -- present".into()));
+    let mut resume = Resume::new();
+    resume.push(section);
+
+    let mut options = RenderOptions::new();
+    options
+        .date_format(DateFormat::Long)
+        .language(Language::German);
+    let doc = latex::print(&resume.render(&options).document).unwrap();
+    assert!(doc.contains(r#"\cventry{M\"{a}rz 2019--heute}{Engineer}"#));
+    assert!(doc.contains(r"\cventry{Mar 2019 -- present}{Engineer}"));
+}
+
+#[test]
+fn test_years() {
+    let years = Years::from("Sep 2020 -- present");
+    let range = DateRange::new(Date::with_month(2020, 9).unwrap()).present();
+    assert_eq!(years, Years::Text(TexStr::text("Sep 2020 -- present")));
+    assert_eq!(years.range(), Some(range));
+    assert_eq!(
+        years.to_tex(DateFormat::Long, Language::German),
+        TexStr::text("Sep 2020 -- present")
+    );
+
+    let years = Years::from(range);
+    assert_eq!(years.range(), Some(range));
+    assert_eq!(
+        years.to_tex(DateFormat::Iso, Language::English),
+        TexStr::raw("2020-09--present")
+    );
+
+    let years = Years::from("year--year");
+    assert_eq!(years.range(), None);
+
+    let years = Years::from(TexStr::raw("2019"));
+    assert_eq!(years.range(), None);
+}
+
+#[test]
+fn test_order() {
+    let mut ranges = [
+        "2020--present",
+        "2019--2021",
+        "2020",
+        "2020--2022",
+        "2019-06",
+    ]
+    .iter()
+    .map(|s| s.parse::<DateRange>().unwrap())
+    .collect::<Vec<_>>();
+    ranges.sort();
+
+    let ranges = ranges.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        ranges,
+        [
+            "2019--2021",
+            "2019-06",
+            "2020",
+            "2020--2022",
+            "2020--present"
+        ]
+    );
+}
@@ -0,0 +1,97 @@
+use latex::Element;
+use moderncv::description::{Bullet, Description};
+use moderncv::section::{cventry, cvitem};
+use moderncv::TexStr;
+
+const BEGIN: &str =
+    r"\begin{itemize}\setlength{\itemsep}{0pt}\setlength{\parskip}{0pt}\setlength{\parsep}{0pt}";
+
+#[test]
+fn test_paragraphs() {
+    let left = Description::from("R&D").to_tex();
+    assert_eq!(left, TexStr::text("R&D"));
+
+    let left = Description::new()
+        .paragraph("First")
+        .paragraph(TexStr::raw(r"\emph{Second}"))
+        .to_tex();
+    assert_eq!(left, TexStr::raw(r"First\newline{}\emph{Second}"));
+}
+
+#[test]
+fn test_bullets() {
+    let mut lead = Bullet::new("Led the team");
+    lead.push("Hired 5 engineers").push("Cut costs by 50%");
+
+    let description = Description::new()
+        .paragraph("Summary")
+        .bullets(vec![lead, "Shipped v2".into()])
+        .paragraph("Outro")
+        .clone();
+
+    let right = [
+        "Summary",
+        BEGIN,
+        r"\item Led the team",
+        BEGIN,
+        r"\item Hired 5 engineers",
+        r"\item Cut costs by 50\%",
+        r"\end{itemize}",
+        r"\item Shipped v2",
+        r"\end{itemize}",
+        "Outro",
+    ]
+    .join("\n");
+
+    assert_eq!(description.to_tex(), TexStr::raw(right.clone()));
+
+    let left = cventry(
+        "2020",
+        "Job",
+        "Employer",
+        "City",
+        None,
+        Some(description.to_tex()),
+    );
+    let right = Element::UserDefined(format!(
+        r"\cventry{{2020}}{{Job}}{{Employer}}{{City}}{{}}{{{}}}",
+        right
+    ));
+    assert_eq!(left, right);
+
+    let left = cvitem("Job", Description::new().bullets(vec!["A"]).to_tex());
+    let right = Element::UserDefined(format!(
+        "\\cvitem{{Job}}{{{}\n\\item A\n\\end{{itemize}}}}",
+        BEGIN
+    ));
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_max_depth() {
+    let mut level3 = Bullet::new("3");
+    level3.push("4");
+    let mut level2 = Bullet::new("2");
+    level2.push(level3);
+    let mut level1 = Bullet::new("1");
+    level1.push(level2);
+
+    let description = Description::new().bullets(vec![level1]).clone();
+    let left = description.to_tex();
+    let begins = left.as_str().matches(r"\begin{itemize}").count();
+    let items: Vec<_> = left
+        .as_str()
+        .lines()
+        .filter(|line| line.starts_with(r"\item"))
+        .collect();
+
+    assert_eq!(begins, 3);
+    assert_eq!(items, [r"\item 1", r"\item 2", r"\item 3", r"\item 4"]);
+
+    let flat: Vec<_> = description
+        .flat_bullets()
+        .into_iter()
+        .map(TexStr::as_str)
+        .collect();
+    assert_eq!(flat, ["1", "2", "3", "4"]);
+}
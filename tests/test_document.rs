@@ -0,0 +1,41 @@
+use latex::{Document, Element};
+use moderncv::document::{makecvfooter, makecvtitle};
+use moderncv::section::vspace;
+use moderncv::{document_class, CVDocument};
+
+#[test]
+fn test_cvdocument() {
+    let mut doc = Document::new(document_class());
+    doc.closesection()
+        .emptysection()
+        .pagebreak()
+        .newpage()
+        .vspace("1em");
+
+    let left: Vec<_> = doc.iter().cloned().collect();
+    let right = vec![
+        Element::UserDefined(r"\closesection{}".to_string()),
+        Element::UserDefined(r"\emptysection{}".to_string()),
+        Element::UserDefined(r"\pagebreak{}".to_string()),
+        Element::UserDefined(r"\newpage{}".to_string()),
+        Element::UserDefined(r"\vspace{1em}".to_string()),
+    ];
+
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_makecvtitle() {
+    assert!(makecvtitle().eq(&Element::UserDefined(r"\makecvtitle".to_string())));
+    assert!(makecvfooter().eq(&Element::UserDefined(r"\makecvfooter".to_string())));
+
+    let mut doc = Document::new(document_class());
+    doc.preamble.use_package("geometry");
+    doc.vspace("1em").makecvtitle_at_start().makecvfooter();
+
+    let left: Vec<_> = doc.iter().cloned().collect();
+    let right = vec![makecvtitle(), vspace("1em"), makecvfooter()];
+
+    assert_eq!(left, right);
+    assert_eq!(doc.preamble.iter().count(), 1);
+}
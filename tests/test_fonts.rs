@@ -0,0 +1,75 @@
+use latex::{Preamble, PreambleElement};
+use moderncv::fonts::{FontError, FontPackage, Fonts};
+use moderncv::{CVPreamble, Engine};
+
+fn usepackage(package: &str) -> PreambleElement {
+    PreambleElement::UsePackage {
+        package: package.to_string(),
+        argument: None,
+    }
+}
+
+#[test]
+fn test_font_packages() {
+    let left = Fonts::new(Engine::Pdflatex)
+        .package(FontPackage::LatinModern)
+        .package(FontPackage::Charter)
+        .package(FontPackage::Other("inconsolata".to_string()))
+        .to_elements();
+    let right = vec![
+        usepackage("lmodern"),
+        usepackage("charter"),
+        usepackage("inconsolata"),
+    ];
+
+    assert_eq!(left, Ok(right));
+}
+
+#[test]
+fn test_fontspec() {
+    let left = Fonts::new(Engine::Xelatex)
+        .main_font("TeX Gyre Pagella", &["Ligatures=TeX", "Numbers=OldStyle"])
+        .mono_font("Fira Mono", &[])
+        .to_elements();
+    let right = vec![
+        usepackage("fontspec"),
+        PreambleElement::UserDefined(
+            r"\setmainfont[Ligatures=TeX,Numbers=OldStyle]{TeX Gyre Pagella}".to_string(),
+        ),
+        PreambleElement::UserDefined(r"\setmonofont{Fira Mono}".to_string()),
+    ];
+
+    assert_eq!(left, Ok(right));
+}
+
+#[test]
+fn test_fontspec_pdflatex() {
+    for engine in [Engine::Pdflatex, Engine::Latexmk] {
+        let left = Fonts::new(engine)
+            .sans_font("Source Sans Pro", &[])
+            .to_elements();
+
+        assert_eq!(left, Err(FontError::FontspecUnsupported(engine)));
+    }
+}
+
+#[test]
+fn test_preamble_fonts() {
+    let mut preamble = Preamble::default();
+    preamble
+        .fonts(Fonts::new(Engine::Lualatex).sans_font("Fira Sans", &["Scale=0.9"]))
+        .unwrap();
+
+    let left: Vec<_> = preamble.iter().cloned().collect();
+    let right = vec![
+        usepackage("fontspec"),
+        PreambleElement::UserDefined(r"\setsansfont[Scale=0.9]{Fira Sans}".to_string()),
+    ];
+
+    assert_eq!(left, right);
+
+    let mut preamble = Preamble::default();
+    let left = preamble.fonts(Fonts::new(Engine::Pdflatex).main_font("Charter", &[]));
+
+    assert!(left.is_err());
+}
@@ -0,0 +1,346 @@
+#![cfg(feature = "jsonresume")]
+
+use moderncv::date::Years;
+use moderncv::description::Description;
+use moderncv::jsonresume::*;
+use moderncv::resume::*;
+use moderncv::{PhoneType, SocialType, TexStr};
+
+const JSON: &str = r#"{
+    "$schema": "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json",
+    "basics": {
+        "name": "John Ronald Doe",
+        "label": "Programmer",
+        "image": "https://example.com/photo.jpg",
+        "email": "john@gmail.com",
+        "phone": "(912) 555-4321",
+        "url": "https://johndoe.com",
+        "summary": "A summary of John Doe",
+        "location": {
+            "address": "2712 Broadway St",
+            "postalCode": "CA 94115",
+            "city": "San Francisco",
+            "countryCode": "US"
+        },
+        "profiles": [
+            { "network": "GitHub", "username": "john_doe", "url": "https://github.com/john_doe" },
+            { "network": "Stack Overflow", "username": "jdoe" },
+            { "network": "Mastodon", "username": "@john@example.social" }
+        ]
+    },
+    "work": [{
+        "name": "R&D Company",
+        "position": "President",
+        "location": "San Francisco",
+        "url": "https://company.com",
+        "startDate": "2013-01-01",
+        "summary": "Description",
+        "highlights": ["Started the company", "50% growth"]
+    }],
+    "education": [{
+        "institution": "University",
+        "area": "Software Development",
+        "studyType": "Bachelor",
+        "startDate": "2011-01-01",
+        "endDate": "2013-01-01",
+        "score": "4.0",
+        "courses": ["DB1101 - Basic SQL"]
+    }],
+    "skills": [
+        { "name": "Web Development", "level": "Master", "keywords": ["HTML", "CSS"] },
+        { "name": "Compression", "keywords": ["Mpeg"] },
+        { "name": "Databases", "keywords": ["SQL"] }
+    ],
+    "languages": [{ "language": "English", "fluency": "Native speaker" }],
+    "interests": [{ "name": "Wildlife", "keywords": ["Ferrets", "Unicorns"] }]
+}"#;
+
+#[test]
+fn test_import_basics() {
+    let import = import(JSON).unwrap();
+    let resume = import.resume;
+
+    assert_eq!(resume.title.as_deref(), Some("Programmer"));
+    assert_eq!(resume.personal.firstname, TexStr::text("John Ronald"));
+    assert_eq!(resume.personal.lastname, TexStr::text("Doe"));
+    assert_eq!(resume.personal.photo, None);
+    assert_eq!(resume.contact.homepage, Some("johndoe.com".into()));
+    assert_eq!(
+        resume.contact.address,
+        Some(Address {
+            street: "2712 Broadway St".into(),
+            city: Some("CA 94115 San Francisco".into()),
+            country: Some("US".into()),
+        })
+    );
+    assert_eq!(
+        resume.contact.phones,
+        vec![Phone {
+            number: "(912) 555-4321".into(),
+            phone_type: None::<PhoneType>,
+        }]
+    );
+    assert_eq!(
+        resume.contact.socials,
+        vec![
+            Social {
+                account: "john_doe".into(),
+                social_type: SocialType::Github,
+                url: Some("https://github.com/john_doe".into()),
+            },
+            Social {
+                account: "jdoe".into(),
+                social_type: SocialType::StackOverflow,
+                url: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_import_sections() {
+    let resume = import(JSON).unwrap().resume;
+    let titles: Vec<_> = resume.sections.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Experience",
+            "Education",
+            "Skills",
+            "Languages",
+            "Interests"
+        ]
+    );
+
+    assert_eq!(
+        resume.sections[0].entries[0],
+        CVEntry::Entry {
+            years: "2013-01-01--present".into(),
+            job: "President".into(),
+            employer: "R&D Company".into(),
+            localization: "San Francisco".into(),
+            grade: None,
+            comment: Some(
+                Description::new()
+                    .paragraph("Description")
+                    .bullets(vec!["Started the company", "50% growth"])
+                    .clone()
+            ),
+        }
+    );
+    assert_eq!(
+        resume.sections[1].entries[0],
+        CVEntry::Entry {
+            years: "2011-01-01--2013-01-01".into(),
+            job: "Bachelor in Software Development".into(),
+            employer: "University".into(),
+            localization: "".into(),
+            grade: Some("4.0".into()),
+            comment: Some("DB1101 - Basic SQL".into()),
+        }
+    );
+    assert_eq!(
+        resume.sections[2].entries,
+        vec![
+            CVEntry::DoubleItem {
+                header1: "Web Development".into(),
+                text1: "HTML, CSS (Master)".into(),
+                header2: "Compression".into(),
+                text2: "Mpeg".into(),
+            },
+            CVEntry::Item {
+                header: "Databases".into(),
+                text: "SQL".into(),
+            },
+        ]
+    );
+    assert_eq!(
+        resume.sections[3].entries[0],
+        CVEntry::Language {
+            name: "English".into(),
+            level: "Native speaker".into(),
+            comment: "".into(),
+        }
+    );
+}
+
+#[test]
+fn test_import_warnings() {
+    let warnings = import(JSON).unwrap().warnings;
+
+    assert_eq!(
+        warnings,
+        vec![
+            Warning::UnsupportedField {
+                path: "$schema".to_string()
+            },
+            Warning::RemoteImage {
+                path: "basics.image".to_string(),
+                url: "https://example.com/photo.jpg".to_string()
+            },
+            Warning::UnknownNetwork {
+                path: "basics.profiles[2]".to_string(),
+                network: "Mastodon".to_string()
+            },
+            Warning::UnsupportedField {
+                path: "work[0].url".to_string()
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[2].to_string(),
+        "basics.profiles[2]: unknown network `Mastodon`, profile skipped"
+    );
+}
+
+#[test]
+fn test_export_roundtrip() {
+    let json = r#"{
+        "basics": {
+            "name": "John Doe",
+            "label": "Programmer",
+            "image": "photo.jpg",
+            "email": "john@gmail.com",
+            "phone": "(912) 555-4321",
+            "url": "https://johndoe.com",
+            "summary": "A summary of John Doe",
+            "location": {
+                "address": "2712 Broadway St",
+                "city": "San Francisco",
+                "countryCode": "US"
+            },
+            "profiles": [
+                { "network": "GitHub", "username": "john_doe", "url": "https://github.com/john_doe" },
+                { "network": "Google Scholar", "username": "jdoe" }
+            ]
+        },
+        "work": [{
+            "name": "R&D Company",
+            "position": "President",
+            "location": "San Francisco",
+            "startDate": "2013-01-01",
+            "summary": "Description",
+            "highlights": ["Started the company", "50% growth"]
+        }, {
+            "name": "Startup",
+            "position": "Intern",
+            "startDate": "2012-06",
+            "endDate": "2012-06"
+        }],
+        "education": [{
+            "institution": "University",
+            "studyType": "Bachelor",
+            "startDate": "2011-01-01",
+            "endDate": "2013-01-01",
+            "score": "4.0",
+            "courses": ["DB1101 - Basic SQL"]
+        }],
+        "skills": [
+            { "name": "Web Development", "level": "Master", "keywords": ["HTML", "CSS"] },
+            { "name": "Compression", "keywords": ["Mpeg"] }
+        ],
+        "languages": [{ "language": "English", "fluency": "Native speaker" }],
+        "interests": [{ "name": "Wildlife", "keywords": ["Ferrets", "Unicorns"] }]
+    }"#;
+
+    let import = import(json).unwrap();
+    assert!(import.warnings.is_empty());
+
+    let left = JsonResume::from_resume(&import.resume);
+    let right: JsonResume = serde_json::from_str(json).unwrap();
+    assert_eq!(left, right);
+
+    let exported = export(&import.resume).unwrap();
+    assert_eq!(moderncv::jsonresume::import(&exported).unwrap(), import);
+
+    let entry = |years: Years| CVEntry::Entry {
+        years,
+        job: "Engineer".into(),
+        employer: "Company".into(),
+        localization: "".into(),
+        grade: None,
+        comment: None,
+    };
+    let mut experience = ResumeSection::new("Experience");
+    experience
+        .push(entry("2019".into()))
+        .push(entry(Years::Range("2018".parse().unwrap())))
+        .push(entry("Jan 2019 -- present".into()))
+        .push(entry("year--year".into()));
+    let mut resume = Resume::new();
+    resume.push(experience);
+
+    let work = JsonResume::from_resume(&resume).work;
+    let dates = work
+        .iter()
+        .map(|work| (work.start_date.as_deref(), work.end_date.as_deref()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dates,
+        [
+            (Some("2019"), Some("2019")),
+            (Some("2018"), Some("2018")),
+            (Some("2019-01"), None),
+            (None, None),
+        ]
+    );
+    assert_eq!(work[3].summary.as_deref(), Some("year--year"));
+
+    let reimport = moderncv::jsonresume::import(&export(&resume).unwrap()).unwrap();
+    let years = match &reimport.resume.sections[0].entries[..2] {
+        [CVEntry::Entry { years: first, .. }, CVEntry::Entry { years: second, .. }] => {
+            (first.range(), second.range())
+        }
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        years,
+        (Some("2019".parse().unwrap()), Some("2018".parse().unwrap()))
+    );
+}
+
+#[test]
+fn test_export_sections() {
+    let mut resume = Resume::new();
+    resume.personal.firstname = "John".into();
+    resume.personal.lastname = "Doe".into();
+
+    let mut awards = ResumeSection::new("Honors & Awards");
+    awards.push(CVEntry::Entry {
+        years: "2019".into(),
+        job: "Best paper".into(),
+        employer: "Conference".into(),
+        localization: "".into(),
+        grade: None,
+        comment: None,
+    });
+    resume.push(awards);
+
+    let mut references = ResumeSection::new("References");
+    references.push(CVEntry::Item {
+        header: "Jane Doe".into(),
+        text: "A great colleague".into(),
+    });
+    resume.push(references);
+
+    let json = JsonResume::from_resume(&resume);
+    assert_eq!(json.basics.name.as_deref(), Some("John Doe"));
+    assert_eq!(
+        json.awards,
+        vec![Award {
+            title: "Best paper".to_string(),
+            date: Some("2019".to_string()),
+            awarder: Some("Conference".to_string()),
+            ..Award::default()
+        }]
+    );
+    assert_eq!(
+        json.references,
+        vec![Reference {
+            name: "Jane Doe".to_string(),
+            reference: "A great colleague".to_string(),
+            ..Reference::default()
+        }]
+    );
+    assert!(json.work.is_empty());
+}
@@ -0,0 +1,126 @@
+use latex::{Document, Element};
+use moderncv::letter::*;
+use moderncv::{document_class, Resume, TexStr};
+
+#[test]
+fn test_recipient() {
+    let left = recipient("HR & Co", TexStr::raw(r"12 street\\City"));
+    let right = Element::UserDefined(r"\recipient{HR \& Co}{12 street\\City}".to_string());
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_date() {
+    let left = date("January 01, 1984");
+    let right = Element::UserDefined(r"\date{January 01, 1984}".to_string());
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_opening_closing() {
+    let left = opening("Dear Sir or Madam,");
+    let right = Element::UserDefined(r"\opening{Dear Sir or Madam,}".to_string());
+
+    assert!(left.eq(&right));
+
+    let left = closing("Yours faithfully,");
+    let right = Element::UserDefined(r"\closing{Yours faithfully,}".to_string());
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_enclosure() {
+    let left = enclosure(Some("Attached".into()), "curriculum vitae");
+    let right = Element::UserDefined(r"\enclosure[Attached]{curriculum vitae}".to_string());
+
+    assert!(left.eq(&right));
+
+    let left = enclosure(None, "curriculum vitae");
+    let right = Element::UserDefined(r"\enclosure{curriculum vitae}".to_string());
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_makeletter() {
+    assert!(makelettertitle().eq(&Element::UserDefined(r"\makelettertitle".to_string())));
+    assert!(makeletterclosing().eq(&Element::UserDefined(r"\makeletterclosing".to_string())));
+}
+
+#[test]
+fn test_cvletter() {
+    let mut doc = Document::new(document_class());
+    doc.recipient("Company", "Address")
+        .opening("Dear Sir,")
+        .closing("Regards,")
+        .makelettertitle()
+        .makeletterclosing();
+
+    let elements: Vec<_> = doc.iter().cloned().collect();
+    assert_eq!(
+        elements,
+        vec![
+            recipient("Company", "Address"),
+            opening("Dear Sir,"),
+            closing("Regards,"),
+            makelettertitle(),
+            makeletterclosing(),
+        ]
+    );
+}
+
+fn letter() -> Letter {
+    Letter {
+        recipient: "Company Recruitment team".into(),
+        address: vec!["Company, Inc.".into(), "123 somestreet".into()],
+        date: None,
+        opening: "Dear Sir or Madam,".into(),
+        closing: "Yours faithfully,".into(),
+        enclosure: Some(Enclosure {
+            label: None,
+            text: "curriculum vitae".into(),
+        }),
+        body: vec!["First paragraph.".into(), "100% motivated.".into()],
+    }
+}
+
+#[test]
+fn test_letter_to_elements() {
+    let elements = letter().to_elements();
+    let right = vec![
+        Element::UserDefined(
+            r"\recipient{Company Recruitment team}{Company, Inc.\\123 somestreet}".to_string(),
+        ),
+        Element::UserDefined(r"\opening{Dear Sir or Madam,}".to_string()),
+        Element::UserDefined(r"\closing{Yours faithfully,}".to_string()),
+        Element::UserDefined(r"\enclosure{curriculum vitae}".to_string()),
+        Element::UserDefined(r"\makelettertitle".to_string()),
+        Element::UserDefined("First paragraph.\n\n100\\% motivated.".to_string()),
+        Element::UserDefined(r"\makeletterclosing".to_string()),
+    ];
+
+    assert_eq!(elements, right);
+}
+
+#[test]
+fn test_resume_letter() {
+    let mut resume = Resume::new();
+    resume.personal.firstname = "John".into();
+    resume.personal.lastname = "Doe".into();
+
+    let letter = letter();
+
+    let doc = latex::print(&resume.to_letter(&letter)).unwrap();
+    assert!(doc.contains(r"\firstname{John}"));
+    assert!(doc.contains(r"\makelettertitle"));
+    assert!(!doc.contains(r"\makecvtitle"));
+
+    let doc = latex::print(&resume.to_document_with_letter(&letter)).unwrap();
+    let cv = doc.find(r"\makecvtitle").unwrap();
+    let clearpage = doc.find(r"\clearpage").unwrap();
+    let title = doc.find(r"\makelettertitle").unwrap();
+    assert!(cv < clearpage && clearpage < title);
+}
@@ -0,0 +1,75 @@
+use latex::Element;
+use moderncv::link::{escape_url, Link};
+use moderncv::section::cventry;
+use moderncv::{ModernCvVersion, TexStr};
+
+#[test]
+fn test_escape_url() {
+    assert_eq!(
+        escape_url("https://example.com/a_b"),
+        "https://example.com/a_b"
+    );
+    assert_eq!(
+        escape_url("https://example.com/~jdoe/a b?q=50%#top"),
+        r"https://example.com/\%7Ejdoe/a\%20b?q=50\%\#top"
+    );
+    assert_eq!(
+        escape_url("https://example.com/ü"),
+        r"https://example.com/\%C3\%BC"
+    );
+}
+
+#[test]
+fn test_href() {
+    let link = Link::href("https://github.com/jdoe/my_project#readme", "my_project");
+    assert_eq!(
+        TexStr::from(&link),
+        TexStr::raw(r"\href{https://github.com/jdoe/my_project\#readme}{my\_project}")
+    );
+
+    let left = cventry("2021", link, "Open source", "", None, None);
+    let right = Element::UserDefined(
+        r"\cventry{2021}{\href{https://github.com/jdoe/my_project\#readme}{my\_project}}{Open source}{}{}{}"
+            .to_string(),
+    );
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_httplink() {
+    let v2 = |link: Link| link.to_tex(ModernCvVersion::V2).as_str().to_string();
+    assert_eq!(
+        v2(Link::http("www.example.com/my_page")),
+        r"\httplink[www.example.com/my\_page]{www.example.com/my_page}"
+    );
+    assert_eq!(
+        v2(Link::http("https://example.com").text("Site")),
+        r"\httpslink[Site]{example.com}"
+    );
+    assert_eq!(
+        v2(Link::http("http://example.com").text("Site")),
+        r"\httplink[Site]{example.com}"
+    );
+
+    assert_eq!(
+        Link::http("https://example.com").text("Site").to_string(),
+        r"\href{https://example.com}{Site}"
+    );
+    assert_eq!(
+        Link::http("example.com").to_string(),
+        r"\href{http://example.com}{example.com}"
+    );
+}
+
+#[test]
+fn test_emaillink() {
+    assert_eq!(
+        Link::email("john_doe@example.com").to_tex(ModernCvVersion::V2),
+        TexStr::raw(r"\emaillink[john\_doe@example.com]{john_doe@example.com}")
+    );
+
+    assert_eq!(
+        Link::email("jdoe@example.com").text("Mail me").to_string(),
+        r"\href{mailto:jdoe@example.com}{Mail me}"
+    );
+}
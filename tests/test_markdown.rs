@@ -0,0 +1,85 @@
+#![cfg(feature = "markdown")]
+
+use latex::Element;
+use moderncv::markdown::to_latex;
+use moderncv::resume::{CVEntry, ResumeSection};
+use moderncv::section::cvitem;
+use moderncv::{RenderOptions, Resume, TexStr};
+
+#[test]
+fn test_inline() {
+    assert_eq!(
+        to_latex("**Rust** and _C_, `a_b` & 50%"),
+        r"\textbf{Rust} and \emph{C}, \texttt{a\_b} \& 50\%"
+    );
+    assert_eq!(
+        to_latex("See [my site](https://example.com/a%20b#top)"),
+        r"See \href{https://example.com/a\%20b\#top}{my site}"
+    );
+    assert_eq!(to_latex("my_account"), r"my\_account");
+    assert_eq!(to_latex("First\n\nSecond"), r"First\newline{}Second");
+}
+
+#[test]
+fn test_unsupported() {
+    assert_eq!(to_latex("# Title"), r"\# Title");
+    assert_eq!(to_latex("- a\n- b"), "- a\n- b");
+    assert_eq!(to_latex("Logo ![alt](logo.png)"), "Logo ![alt](logo.png)");
+    assert_eq!(to_latex("a <b>c</b>"), r"a <b>c</b>");
+}
+
+#[test]
+fn test_free_text() {
+    let mut section = ResumeSection::new("Experience");
+    section
+        .push(CVEntry::Item {
+            header: "Skills".into(),
+            text: "**Rust**".into(),
+        })
+        .push(CVEntry::Item {
+            header: "Raw".into(),
+            text: TexStr::raw("**Rust**"),
+        })
+        .push(CVEntry::Entry {
+            years: "2020--2021".into(),
+            job: "Engineer".into(),
+            employer: "R&D".into(),
+            localization: "City".into(),
+            grade: None,
+            comment: Some("Built _things_".into()),
+        });
+    let mut resume = Resume::new();
+    resume.personal.quote = Some("*Stay* curious".into());
+    resume.push(section);
+
+    let doc = latex::print(&resume.to_document()).unwrap();
+    assert!(doc.contains(r"\cvitem{Skills}{**Rust**}"));
+    assert!(doc.contains(r"\quote{*Stay* curious}"));
+
+    let rendered = resume.render(RenderOptions::new().markdown(true));
+    let doc = latex::print(&rendered.document).unwrap();
+    assert!(doc.contains(r"\cvitem{Skills}{\textbf{Rust}}"));
+    assert!(doc.contains(r"\cvitem{Raw}{**Rust**}"));
+    assert!(doc.contains(r"\cventry{2020--2021}{Engineer}{R\&D}{City}{}{Built \emph{things}}"));
+    assert!(doc.contains(r"\quote{\emph{Stay} curious}"));
+}
+
+#[test]
+fn test_helpers() {
+    let left = cvitem("Skills", "**Rust**");
+    assert_eq!(
+        left,
+        Element::UserDefined(r"\cvitem{Skills}{**Rust**}".to_string())
+    );
+
+    let left = cvitem("Skills", TexStr::markdown("**Rust**"));
+    assert_eq!(
+        left,
+        Element::UserDefined(r"\cvitem{Skills}{\textbf{Rust}}".to_string())
+    );
+}
+
+#[test]
+fn test_texstr_markdown() {
+    assert_eq!(TexStr::markdown("**a**"), TexStr::raw(r"\textbf{a}"));
+}
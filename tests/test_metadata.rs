@@ -0,0 +1,100 @@
+use latex::{Preamble, PreambleElement};
+use moderncv::metadata::Metadata;
+use moderncv::{CVPreamble, Resume, TexStr};
+
+#[test]
+fn test_empty() {
+    assert_eq!(Metadata::new().to_element(), None);
+
+    let mut preamble = Preamble::default();
+    preamble.metadata(&Metadata::new());
+    assert_eq!(preamble.iter().count(), 0);
+}
+
+#[test]
+fn test_to_element() {
+    let mut metadata = Metadata::new();
+    metadata
+        .title("Resume")
+        .author("John Doe")
+        .subject("R&D engineer")
+        .keyword("Rust")
+        .keyword("C#");
+
+    let right = PreambleElement::UserDefined(
+        r"\AtBeginDocument{\hypersetup{
+  pdftitle={Resume},
+  pdfauthor={John Doe},
+  pdfsubject={R\&D engineer},
+  pdfkeywords={Rust, C\#}
+}}"
+        .to_string(),
+    );
+    assert_eq!(metadata.to_element(), Some(right));
+}
+
+#[test]
+fn test_defaults() {
+    let mut preamble = Preamble::default();
+    preamble.title("Resume");
+    preamble.name("John", "Doe");
+
+    let metadata = Metadata::new().with_defaults(&preamble);
+    assert_eq!(metadata.title, Some(TexStr::raw("Resume")));
+    assert_eq!(metadata.author, None);
+
+    let metadata = Metadata::new()
+        .title("CV")
+        .author("J. Doe")
+        .with_defaults(&preamble);
+    assert_eq!(metadata.title, Some("CV".into()));
+    assert_eq!(metadata.author, Some("J. Doe".into()));
+}
+
+#[test]
+fn test_preamble() {
+    let mut preamble = Preamble::default();
+    preamble
+        .name("Jane", "Doe_Smith")
+        .metadata(Metadata::new().author("Jane Doe_Smith").keyword("Rust"));
+
+    let right = PreambleElement::UserDefined(
+        r"\AtBeginDocument{\hypersetup{
+  pdfauthor={Jane Doe\_Smith},
+  pdfkeywords={Rust}
+}}"
+        .to_string(),
+    );
+    assert_eq!(preamble.iter().last(), Some(&right));
+}
+
+#[test]
+fn test_resume() {
+    let mut resume = Resume::new();
+    resume.title = Some("Resume".to_string());
+    resume.personal.firstname = "John".into();
+    resume.personal.lastname = "Doe_Smith".into();
+    resume.metadata = Some(Metadata::new().subject("Engineer").clone());
+
+    let doc = resume.to_document();
+    let right = PreambleElement::UserDefined(
+        r"\AtBeginDocument{\hypersetup{
+  pdftitle={Resume},
+  pdfauthor={John Doe\_Smith},
+  pdfsubject={Engineer}
+}}"
+        .to_string(),
+    );
+    assert_eq!(doc.preamble.iter().last(), Some(&right));
+
+    resume.metadata = Some(Metadata::new().author(TexStr::raw(r"J. Doe")).clone());
+    let doc = resume.to_document();
+    let right = PreambleElement::UserDefined(
+        r"\AtBeginDocument{\hypersetup{
+  pdftitle={Resume},
+  pdfauthor={J. Doe}
+}}"
+        .to_string(),
+    );
+    assert_eq!(doc.preamble.iter().last(), Some(&right));
+}
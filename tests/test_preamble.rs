@@ -1,5 +1,6 @@
 use latex::PreambleElement;
 use moderncv::preamble::*;
+use moderncv::TexStr;
 
 #[test]
 fn test_name() {
@@ -38,11 +39,11 @@ fn test_familyname() {
 
 #[test]
 fn test_address() {
-    let left = address("12 somestreet", Some("3456 somecity"), None);
+    let left = address("12 somestreet", Some("3456 somecity".into()), None);
     let right = PreambleElement::UserDefined(r"\address{12 somestreet}{3456 somecity}".to_string());
     assert!(left.eq(&right));
 
-    let left = address("12 somestreet", None, Some("unused"));
+    let left = address("12 somestreet", None, Some("unused".into()));
     let right = PreambleElement::UserDefined(r"\address{12 somestreet}".to_string());
     assert!(left.eq(&right));
 }
@@ -73,20 +74,40 @@ fn test_email() {
     let left = email("jdoe@design.org");
     let right = PreambleElement::UserDefined(r"\email{jdoe@design.org}".to_string());
     assert!(left.eq(&right));
+
+    let left = email("j_doe@design.org");
+    let right = PreambleElement::UserDefined(r"\email{j\_doe@design.org}".to_string());
+    assert!(left.eq(&right));
 }
 
 #[test]
 fn test_social() {
-    let left = social(r"my\_account", SocialType::Github, None);
+    let left = social("my_account", SocialType::Github, None);
     let right = PreambleElement::UserDefined(r"\social[github]{my\_account}".to_string());
     assert!(left.eq(&right));
+
+    let left = social(
+        "jdoe",
+        SocialType::Github,
+        Some("https://example.com/~jdoe#about".into()),
+    );
+    let right = PreambleElement::UserDefined(
+        r"\social[github][https://example.com/\%7Ejdoe\#about]{jdoe}".to_string(),
+    );
+    assert!(left.eq(&right));
 }
 
 #[test]
 fn test_homepage() {
-    let left = homepage(r"https://github.com/my\_home");
+    let left = homepage("https://github.com/my_home");
     let right = PreambleElement::UserDefined(r"\homepage{https://github.com/my\_home}".to_string());
     assert!(left.eq(&right));
+
+    let left = homepage("https://example.com/~jdoe/100%");
+    let right = PreambleElement::UserDefined(
+        r"\homepage{https://example.com/\textasciitilde{}jdoe/100\%}".to_string(),
+    );
+    assert!(left.eq(&right));
 }
 
 #[test]
@@ -100,9 +121,54 @@ fn test_cvtheme() {
     assert!(left.eq(&right));
 }
 
+#[test]
+fn test_moderncvstyle() {
+    let left = moderncvstyle(Style::Classic);
+    let right = PreambleElement::UserDefined(r"\moderncvstyle{classic}".to_string());
+    assert!(left.eq(&right));
+
+    let left = moderncvcolor(Color::Green);
+    let right = PreambleElement::UserDefined(r"\moderncvcolor{green}".to_string());
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_definecolor() {
+    let color = CustomColor::html("#1a2B3c").unwrap();
+    let left = definecolor(ColorSlot::Color1, &color);
+    let right = PreambleElement::UserDefined(r"\definecolor{color1}{HTML}{1A2B3C}".to_string());
+    assert!(left.eq(&right));
+
+    let color = CustomColor::rgb(0.1, 0.5, 1.0).unwrap();
+    let left = definecolor(ColorSlot::Color2, &color);
+    let right = PreambleElement::UserDefined(r"\definecolor{color2}{rgb}{0.1,0.5,1}".to_string());
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_custom_color() {
+    assert!(CustomColor::html("#12345").is_err());
+    assert!(CustomColor::html("#12345G").is_err());
+    assert!(CustomColor::rgb(0.1, 1.5, 0.0).is_err());
+    assert!(CustomColor::rgb(-0.1, 0.5, 0.0).is_err());
+
+    let color: CustomColor = "rgb(0.2, 0.4, 0.6)".parse().unwrap();
+    assert_eq!(color, CustomColor::rgb(0.2, 0.4, 0.6).unwrap());
+    assert_eq!(color.to_string(), "rgb(0.2,0.4,0.6)");
+
+    let color: CustomColor = "#00ff7f".parse().unwrap();
+    assert_eq!(color.to_string(), "#00FF7F");
+
+    assert_eq!(
+        "rgb(0.2, 0.4)".parse::<CustomColor>(),
+        Err(ColorError::InvalidRgb("rgb(0.2, 0.4)".to_string()))
+    );
+    assert!("rgb(1, 2, 3)".parse::<CustomColor>().is_err());
+}
+
 #[test]
 fn test_extrainfo() {
-    let left = extrainfo(r"\weblink{www.ctan.org}");
+    let left = extrainfo(TexStr::raw(r"\weblink{www.ctan.org}"));
     let right = PreambleElement::UserDefined(r"\extrainfo{\weblink{www.ctan.org}}".to_string());
     assert!(left.eq(&right));
 }
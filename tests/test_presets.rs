@@ -0,0 +1,66 @@
+use latex::{Preamble, PreambleElement};
+use moderncv::presets::Presets;
+use moderncv::{CVPreamble, Engine};
+
+fn usepackage(package: &str, argument: &str) -> PreambleElement {
+    PreambleElement::UsePackage {
+        package: package.to_string(),
+        argument: Some(argument.to_string()),
+    }
+}
+
+#[test]
+fn test_pdflatex_defaults() {
+    let left = Presets::new(Engine::Pdflatex).to_elements();
+    let right = vec![
+        usepackage("inputenc", "utf8"),
+        usepackage("fontenc", "T1"),
+        usepackage("geometry", "scale=0.75"),
+    ];
+
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_unicode_engines() {
+    for engine in [Engine::Xelatex, Engine::Lualatex] {
+        let left = Presets::new(engine).to_elements();
+        let right = vec![usepackage("geometry", "scale=0.75")];
+
+        assert_eq!(left, right);
+    }
+}
+
+#[test]
+fn test_options() {
+    let left = Presets::new(Engine::Lualatex)
+        .margins("2cm", "2cm", "1.5cm", "1.5cm")
+        .babel("ngerman")
+        .hintscolumnwidth("3cm")
+        .to_elements();
+    let right = vec![
+        usepackage("geometry", "top=2cm,bottom=2cm,left=1.5cm,right=1.5cm"),
+        usepackage("babel", "ngerman"),
+        PreambleElement::UserDefined(r"\setlength{\hintscolumnwidth}{3cm}".to_string()),
+    ];
+
+    assert_eq!(left, right);
+
+    let left = Presets::new(Engine::Xelatex)
+        .scale(0.8)
+        .no_geometry()
+        .to_elements();
+
+    assert!(left.is_empty());
+}
+
+#[test]
+fn test_preamble_presets() {
+    let mut preamble = Preamble::default();
+    preamble.presets(Presets::new(Engine::Xelatex).scale(0.8));
+
+    let left: Vec<_> = preamble.iter().cloned().collect();
+    let right = vec![usepackage("geometry", "scale=0.8")];
+
+    assert_eq!(left, right);
+}
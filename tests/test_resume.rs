@@ -0,0 +1,234 @@
+use latex::{Document, Element, Section};
+use moderncv::preamble::{ColorSlot, CustomColor};
+use moderncv::resume::*;
+use moderncv::{
+    document_class, CVDocument, CVPreamble, CVSection, Color, ModernCvVersion, PhoneType,
+    RenderOptions, SocialType, Style, TitlePlacement,
+};
+
+fn make_resume() -> Resume {
+    let mut resume = Resume::new();
+    resume.title = Some("R&D resume".to_string());
+    resume.theme = Some(Theme {
+        style: Style::Casual,
+        color: Some(Color::Green),
+        color1: Some(CustomColor::html("#1A2B3C").unwrap()),
+        color2: None,
+    });
+    resume.personal = Personal {
+        firstname: "John".into(),
+        lastname: "Doe".into(),
+        photo: Some(Photo {
+            file: "picture".to_string(),
+            width: Some("64pt".to_string()),
+            frame: None,
+        }),
+        quote: Some("Any intelligent fool can make things bigger".into()),
+        extrainfo: None,
+    };
+    resume.contact = Contact {
+        address: Some(Address {
+            street: "12 somestreet".into(),
+            city: Some("3456 somecity".into()),
+            country: None,
+        }),
+        phones: vec![Phone {
+            number: "+123 456 7890".into(),
+            phone_type: Some(PhoneType::Mobile),
+        }],
+        email: Some("jdoe@design.org".into()),
+        homepage: None,
+        socials: vec![Social {
+            account: "j_doe".into(),
+            social_type: SocialType::Github,
+            url: None,
+        }],
+    };
+
+    let mut education = ResumeSection::new("Education");
+    education.push(CVEntry::Entry {
+        years: "2020--2021".into(),
+        job: "Degree".into(),
+        employer: "Institution".into(),
+        localization: "City".into(),
+        grade: None,
+        comment: Some("Description".into()),
+    });
+    resume.push(education);
+
+    let mut languages = ResumeSection::new("Languages");
+    languages
+        .push(CVEntry::Language {
+            name: "English".into(),
+            level: "Fluent".into(),
+            comment: "".into(),
+        })
+        .push(CVEntry::ListItem {
+            item: "Item 1".into(),
+        });
+    resume.push(languages);
+
+    resume
+}
+
+#[test]
+fn test_to_document() {
+    let left = make_resume().to_document();
+
+    let mut right = Document::new(document_class());
+    right
+        .preamble
+        .title(r"R\&D resume")
+        .cvtheme(Style::Casual, Some(Color::Green))
+        .definecolor(ColorSlot::Color1, &CustomColor::html("1a2b3c").unwrap())
+        .firstname("John")
+        .familyname("Doe")
+        .photo("picture", Some("64pt"), None)
+        .address("12 somestreet", Some("3456 somecity".into()), None)
+        .phone("+123 456 7890", Some(PhoneType::Mobile))
+        .email("jdoe@design.org")
+        .social("j_doe", SocialType::Github, None)
+        .quote("Any intelligent fool can make things bigger");
+    right.makecvtitle();
+
+    let mut education = Section::new("Education");
+    education.cventry(
+        "2020--2021",
+        "Degree",
+        "Institution",
+        "City",
+        None,
+        Some("Description".into()),
+    );
+    right.push(education);
+
+    let mut languages = Section::new("Languages");
+    languages
+        .cvlanguage("English", "Fluent", "")
+        .cvlistitem("Item 1");
+    right.push(languages);
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_render_v2() {
+    let left = make_resume()
+        .render(RenderOptions::new().version(ModernCvVersion::V2))
+        .document;
+
+    let mut right = Document::new(document_class());
+    right
+        .preamble
+        .title(r"R\&D resume")
+        .moderncvstyle(Style::Casual)
+        .moderncvcolor(Color::Green)
+        .definecolor(ColorSlot::Color1, &CustomColor::html("1a2b3c").unwrap())
+        .name("John", "Doe")
+        .photo("picture", Some("64pt"), None)
+        .address("12 somestreet", Some("3456 somecity".into()), None)
+        .phone("+123 456 7890", Some(PhoneType::Mobile))
+        .email("jdoe@design.org")
+        .social("j_doe", SocialType::Github, None)
+        .quote("Any intelligent fool can make things bigger");
+    right.makecvtitle();
+
+    let mut education = Section::new("Education");
+    education.cventry(
+        "2020--2021",
+        "Degree",
+        "Institution",
+        "City",
+        None,
+        Some("Description".into()),
+    );
+    right.push(education);
+
+    let mut languages = Section::new("Languages");
+    languages
+        .cvitemwithcomment("English", "Fluent", "")
+        .cvlistitem("Item 1");
+    right.push(languages);
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_to_element() {
+    let left = CVEntry::Item {
+        header: "header".into(),
+        text: moderncv::TexStr::raw(r"\emph{text}"),
+    }
+    .to_element();
+    let right = Element::UserDefined(r"\cvitem{header}{\emph{text}}".to_string());
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_subsection() {
+    let mut section = ResumeSection::new("Experience");
+    section
+        .push(CVEntry::Subsection {
+            title: "R&D".to_string(),
+            entries: vec![
+                CVEntry::ListItem {
+                    item: "Item 1".into(),
+                },
+                CVEntry::VSpace {
+                    length: "-0.5em".to_string(),
+                },
+                CVEntry::ListItem {
+                    item: "Item 2".into(),
+                },
+            ],
+        })
+        .push(CVEntry::PageBreak);
+
+    let left: Vec<_> = section.to_section().iter().cloned().collect();
+    let right = vec![
+        Element::UserDefined(
+            "\\subsection{R\\&D}\n\\cvlistitem{Item 1}\n\\vspace{-0.5em}\n\\cvlistitem{Item 2}"
+                .to_string(),
+        ),
+        Element::UserDefined(r"\pagebreak{}".to_string()),
+    ];
+
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_title_placement() {
+    let mut resume = Resume::new();
+    resume.personal.firstname = "John".into();
+    resume.personal.lastname = "Doe".into();
+    resume
+        .push(ResumeSection::new("Education"))
+        .push(ResumeSection::new("Experience"));
+
+    let titles = |placement| {
+        resume
+            .to_document_with_title(placement)
+            .iter()
+            .map(|elem| match elem {
+                Element::Section(section) => section.name.clone(),
+                Element::UserDefined(s) => s.clone(),
+                _ => String::new(),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        titles(TitlePlacement::Start),
+        [r"\makecvtitle", "Education", "Experience"]
+    );
+    assert_eq!(
+        titles(TitlePlacement::BeforeSection(1)),
+        ["Education", r"\makecvtitle", "Experience"]
+    );
+    assert_eq!(
+        titles(TitlePlacement::BeforeSection(5)),
+        ["Education", "Experience", r"\makecvtitle"]
+    );
+    assert_eq!(titles(TitlePlacement::Manual), ["Education", "Experience"]);
+}
@@ -1,5 +1,6 @@
-use latex::Element;
+use latex::{Element, Section};
 use moderncv::section::*;
+use moderncv::{CVSection, TexStr};
 
 #[test]
 fn test_cventry() {
@@ -8,8 +9,8 @@ fn test_cventry() {
         "Degree",
         "Institution",
         "City",
-        Some(r"\textit{Grade}"),
-        Some("Description"),
+        Some(TexStr::raw(r"\textit{Grade}")),
+        Some("Description".into()),
     );
     let right = Element::UserDefined(
         r"\cventry{2020--2021}{Degree}{Institution}{City}{\textit{Grade}}{Description}".to_string(),
@@ -23,13 +24,28 @@ fn test_cventry() {
         "Institution",
         "City",
         None,
-        Some("Description"),
+        Some("Description".into()),
     );
     let right = Element::UserDefined(
         r"\cventry{2020--2021}{Degree}{Institution}{City}{}{Description}".to_string(),
     );
 
     assert!(left.eq(&right));
+
+    let left = cventry(
+        "2020--2021",
+        "R&D Lead",
+        "Smith & Sons",
+        "City",
+        Some("50%".into()),
+        Some("Cut costs by $1M #savings".into()),
+    );
+    let right = Element::UserDefined(
+        r"\cventry{2020--2021}{R\&D Lead}{Smith \& Sons}{City}{50\%}{Cut costs by \$1M \#savings}"
+            .to_string(),
+    );
+
+    assert!(left.eq(&right));
 }
 
 #[test]
@@ -53,7 +69,7 @@ fn test_cvcomputer() {
 
 #[test]
 fn test_cvline() {
-    let left = cvline("hobby 1", r"\small Description");
+    let left = cvline("hobby 1", TexStr::raw(r"\small Description"));
     let right = Element::UserDefined(r"\cvline{hobby 1}{\small Description}".to_string());
 
     assert!(left.eq(&right));
@@ -98,3 +114,83 @@ fn test_cvitemwithcomment() {
 
     assert!(left.eq(&right));
 }
+
+#[test]
+fn test_spacing() {
+    let left = cventry_with_spacing(
+        Some("-0.5em"),
+        "2020--2021",
+        "Degree",
+        "Institution",
+        "City",
+        None,
+        None,
+    );
+    let right = Element::UserDefined(
+        r"\cventry[-0.5em]{2020--2021}{Degree}{Institution}{City}{}{}".to_string(),
+    );
+
+    assert!(left.eq(&right));
+
+    let left = cvitem_with_spacing(Some("-0.5em"), "header", "text");
+    let right = Element::UserDefined(r"\cvitem[-0.5em]{header}{text}".to_string());
+
+    assert!(left.eq(&right));
+
+    let left = cvlistitem_with_spacing(None, "Item 1");
+    let right = Element::UserDefined(r"\cvlistitem{Item 1}".to_string());
+
+    assert!(left.eq(&right));
+
+    let left = cvlistdoubleitem_with_spacing(Some("1ex"), "Item 1", "Item 2");
+    let right = Element::UserDefined(r"\cvlistdoubleitem[1ex]{Item 1}{Item 2}".to_string());
+
+    assert!(left.eq(&right));
+
+    let left = cvitemwithcomment_with_spacing(Some("-1em"), "header", "text", "comment");
+    let right =
+        Element::UserDefined(r"\cvitemwithcomment[-1em]{header}{text}{comment}".to_string());
+
+    assert!(left.eq(&right));
+}
+
+#[test]
+fn test_section_spacing() {
+    let mut section = Section::new("Skills");
+    section
+        .cvdoubleitem_with_spacing(Some("-0.5em"), "header1", "text1", "header2", "text2")
+        .cvitem("header", "text");
+
+    let left: Vec<_> = section.iter().cloned().collect();
+    let right = vec![
+        Element::UserDefined(r"\cvdoubleitem[-0.5em]{header1}{text1}{header2}{text2}".to_string()),
+        Element::UserDefined(r"\cvitem{header}{text}".to_string()),
+    ];
+
+    assert_eq!(left, right);
+}
+
+#[test]
+fn test_subsection() {
+    let left = subsection("R&D");
+    let right = Element::UserDefined(r"\subsection{R\&D}".to_string());
+
+    assert!(left.eq(&right));
+
+    let mut vocational = Section::new("R&D");
+    vocational.cvlistitem("Item 1").vspace(r"-\baselineskip");
+
+    let mut experience = Section::new("Experience");
+    experience.subsection(&vocational).newpage().pagebreak();
+
+    let left: Vec<_> = experience.iter().cloned().collect();
+    let right = vec![
+        Element::UserDefined(r"\subsection{R\&D}".to_string()),
+        Element::UserDefined(r"\cvlistitem{Item 1}".to_string()),
+        Element::UserDefined(r"\vspace{-\baselineskip}".to_string()),
+        Element::UserDefined(r"\newpage{}".to_string()),
+        Element::UserDefined(r"\pagebreak{}".to_string()),
+    ];
+
+    assert_eq!(left, right);
+}
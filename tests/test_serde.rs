@@ -0,0 +1,204 @@
+#![cfg(feature = "serde")]
+
+use moderncv::date::{Date, DateRange, Years};
+use moderncv::description::{Bullet, Description};
+use moderncv::preamble::CustomColor;
+use moderncv::resume::*;
+use moderncv::{Color, PhoneType, SocialType, Style, TexStr};
+
+#[test]
+fn test_enums() {
+    assert_eq!(
+        serde_json::to_string(&Style::OldStyle).unwrap(),
+        r#""oldstyle""#
+    );
+    assert_eq!(
+        serde_json::to_string(&Color::Burgundy).unwrap(),
+        r#""burgundy""#
+    );
+    assert_eq!(
+        serde_json::to_string(&PhoneType::Mobile).unwrap(),
+        r#""mobile""#
+    );
+
+    let social: SocialType = serde_json::from_str(r#""stackoverflow""#).unwrap();
+    assert_eq!(social, SocialType::StackOverflow);
+    assert_eq!(social.to_string(), "stackoverflow");
+}
+
+#[test]
+fn test_texstr() {
+    let text: TexStr = serde_json::from_str(r#""R&D""#).unwrap();
+    assert_eq!(text, TexStr::text("R&D"));
+
+    let raw: TexStr = serde_json::from_str(r#"{"raw": "\\emph{Title}"}"#).unwrap();
+    assert_eq!(raw, TexStr::raw(r"\emph{Title}"));
+    assert_eq!(
+        serde_json::to_string(&raw).unwrap(),
+        r#"{"raw":"\\emph{Title}"}"#
+    );
+
+    assert!(serde_json::from_str::<TexStr>(r#"{"latex": "x"}"#).is_err());
+
+    let number: TexStr = serde_json::from_str("1234567").unwrap();
+    assert_eq!(number, TexStr::text("1234567"));
+    let number: TexStr = serde_json::from_str("-1.5").unwrap();
+    assert_eq!(number, TexStr::text("-1.5"));
+    let boolean: TexStr = serde_json::from_str("true").unwrap();
+    assert_eq!(boolean, TexStr::text("true"));
+}
+
+#[test]
+fn test_resume() {
+    let json = r#"{
+        "title": "Resume",
+        "theme": { "style": "casual", "color": "green" },
+        "personal": { "firstname": "John", "lastname": "Doe" },
+        "contact": {
+            "phones": [{ "number": "+123 456 7890", "phone_type": "mobile" }],
+            "socials": [{ "account": "jdoe", "social_type": "github" }]
+        },
+        "sections": [{
+            "title": "Education",
+            "entries": [
+                {
+                    "type": "entry",
+                    "years": "2020--2021",
+                    "job": "Degree",
+                    "employer": "Institution",
+                    "localization": "City",
+                    "grade": { "raw": "\\textit{Grade}" }
+                },
+                { "type": "listitem", "item": "Item 1" }
+            ]
+        }]
+    }"#;
+
+    let resume: Resume = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        resume.theme,
+        Some(Theme {
+            style: Style::Casual,
+            color: Some(Color::Green),
+            color1: None,
+            color2: None,
+        })
+    );
+    assert_eq!(resume.contact.socials[0].social_type, SocialType::Github);
+    assert_eq!(
+        resume.sections[0].entries[1],
+        CVEntry::ListItem {
+            item: "Item 1".into()
+        }
+    );
+
+    let serialized = serde_json::to_string(&resume).unwrap();
+    let deserialized: Resume = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(resume, deserialized);
+}
+
+#[test]
+fn test_custom_color() {
+    let json = r##"{ "style": "classic", "color1": "#1a2b3c", "color2": "rgb(0.5, 0.5, 0.5)" }"##;
+    let theme: Theme = serde_json::from_str(json).unwrap();
+    assert_eq!(theme.color1, Some(CustomColor::html("1A2B3C").unwrap()));
+    assert_eq!(theme.color2, Some(CustomColor::rgb(0.5, 0.5, 0.5).unwrap()));
+
+    let serialized = serde_json::to_string(&theme).unwrap();
+    assert!(serialized.contains(r##""color1":"#1A2B3C""##));
+    assert_eq!(serde_json::from_str::<Theme>(&serialized).unwrap(), theme);
+
+    let json = r#"{ "style": "classic", "color1": "blue" }"#;
+    assert!(serde_json::from_str::<Theme>(json).is_err());
+}
+
+#[test]
+fn test_description() {
+    let json = r#"[
+        "Summary",
+        { "bullets": ["Shipped v2", { "text": "Led the team", "bullets": [{ "raw": "\\emph{5} hires" }] }] }
+    ]"#;
+    let left: Description = serde_json::from_str(json).unwrap();
+
+    let mut lead = Bullet::new("Led the team");
+    lead.push(TexStr::raw(r"\emph{5} hires"));
+    let right = Description::new()
+        .paragraph("Summary")
+        .bullets(vec!["Shipped v2".into(), lead])
+        .clone();
+
+    assert_eq!(left, right);
+
+    let serialized = serde_json::to_string(&left).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Description>(&serialized).unwrap(),
+        left
+    );
+
+    let e = serde_json::from_str::<Description>(r#"[{ "bullets": [{ "txt": "a" }] }]"#);
+    assert!(e
+        .unwrap_err()
+        .to_string()
+        .starts_with("unknown field `txt`, expected one of `text`, `bullets`, `raw`"));
+
+    let e = serde_json::from_str::<Description>(r#"[{ "bulets": [] }]"#);
+    assert!(e
+        .unwrap_err()
+        .to_string()
+        .starts_with("unknown field `bulets`, expected `bullets` or `raw`"));
+
+    let numbers: Description = serde_json::from_str(r#"[{ "bullets": [2019, 3.5] }]"#).unwrap();
+    assert_eq!(
+        numbers,
+        Description::new().bullets(vec!["2019", "3.5"]).clone()
+    );
+
+    let paragraph: Description = serde_json::from_str(r#""Description""#).unwrap();
+    assert_eq!(paragraph, Description::from("Description"));
+    assert_eq!(
+        serde_json::to_string(&paragraph).unwrap(),
+        r#""Description""#
+    );
+}
+
+#[test]
+fn test_years() {
+    let left: Years = serde_json::from_str(r#""Sep 2020 -- present""#).unwrap();
+    assert_eq!(left, Years::Text("Sep 2020 -- present".into()));
+    assert_eq!(
+        serde_json::to_string(&left).unwrap(),
+        r#""Sep 2020 -- present""#
+    );
+
+    let left: Years = serde_json::from_str(r#"{ "start": "Jan 2019", "end": "present" }"#).unwrap();
+    let right = DateRange::new(Date::with_month(2019, 1).unwrap()).present();
+    assert_eq!(left, Years::Range(right));
+    assert_eq!(
+        serde_json::to_string(&left).unwrap(),
+        r#"{"start":"2019-01","end":"present"}"#
+    );
+
+    let left: Years = serde_json::from_str(r#"{ "start": "2019" }"#).unwrap();
+    assert_eq!(left, Years::Range(DateRange::new(Date::new(2019))));
+    assert_eq!(serde_json::to_string(&left).unwrap(), r#"{"start":"2019"}"#);
+
+    let left = serde_json::from_str::<Years>(r#"{ "start": "2021", "end": "2019" }"#);
+    assert!(left.is_err());
+
+    let left: Years = serde_json::from_str(r#"{ "start": 2019, "end": 2021 }"#).unwrap();
+    assert_eq!(left, Years::Range("2019--2021".parse().unwrap()));
+    let left: Years = serde_json::from_str("2020").unwrap();
+    assert_eq!(left, Years::Text("2020".into()));
+
+    let e = serde_json::from_str::<Years>(r#"{ "start": "2019", "end": "soon" }"#).unwrap_err();
+    assert!(e.to_string().starts_with("end: invalid date `soon`"));
+    let e = serde_json::from_str::<Years>(r#"{ "stat": "2019" }"#).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("unknown field `stat`, expected one of `start`, `end`, `raw`"));
+    let e = serde_json::from_str::<Years>(r#"{ "end": "2019" }"#).unwrap_err();
+    assert!(e.to_string().starts_with("missing field `start`"));
+
+    let left: Years = serde_json::from_str(r#"{ "raw": "\\today" }"#).unwrap();
+    assert_eq!(left, Years::Text(TexStr::raw(r"\today")));
+}
@@ -0,0 +1,176 @@
+#![cfg(feature = "source")]
+
+use moderncv::date::Years;
+use moderncv::resume::*;
+use moderncv::source::*;
+use moderncv::{PhoneType, TexStr};
+
+const YAML: &str = include_str!("../examples/resume.yaml");
+
+#[test]
+fn test_from_str() {
+    let resume = from_str(YAML, Format::Yaml).unwrap();
+
+    assert_eq!(resume.personal.firstname, TexStr::text("John"));
+    assert_eq!(resume.contact.phones[0].phone_type, Some(PhoneType::Mobile));
+    assert_eq!(resume.contact.phones[1].phone_type, None);
+    assert_eq!(resume.sections.len(), 6);
+    assert_eq!(
+        resume.sections[1].entries[0],
+        CVEntry::Line {
+            leftmark: "title".into(),
+            text: TexStr::raw(r"\emph{Title}"),
+        }
+    );
+
+    let rendered = latex::print(&resume.to_document()).unwrap();
+    assert!(
+        rendered.contains(r"\cventry{year--year}{Job title}{R\&D Company}{City}{}{Description}")
+    );
+    assert!(rendered
+        .contains(r"\cventry{year--year}{Degree}{Institution}{City}{\textit{Grade}}{Description}"));
+}
+
+#[test]
+fn test_roundtrip() {
+    let resume = from_str(YAML, Format::Yaml).unwrap();
+
+    for format in [Format::Yaml, Format::Toml, Format::Json].iter() {
+        let s = to_string(&resume, *format).unwrap();
+        assert_eq!(from_str(&s, *format).unwrap(), resume);
+    }
+}
+
+#[test]
+fn test_scalars() {
+    let yaml =
+        "personal:\n  firstname: John\n  lastname: 2\ncontact:\n  phones:\n    - number: 1234567\n";
+    let resume = from_str(yaml, Format::Yaml).unwrap();
+    assert_eq!(resume.personal.lastname, TexStr::text("2"));
+    assert_eq!(resume.contact.phones[0].number, TexStr::text("1234567"));
+
+    let toml = "[personal]\nfirstname = \"John\"\nlastname = \"Doe\"\n\n[[contact.phones]]\nnumber = 1234567\n";
+    let resume = from_str(toml, Format::Toml).unwrap();
+    assert_eq!(resume.contact.phones[0].number, TexStr::text("1234567"));
+}
+
+#[test]
+fn test_unquoted_years() {
+    let entry = |resume: &Resume| match &resume.sections[0].entries[0] {
+        CVEntry::Entry { years, .. } => years.clone(),
+        _ => unreachable!(),
+    };
+    let range = Years::Range("2019--2021".parse().unwrap());
+
+    let yaml = "personal:\n  firstname: John\n  lastname: Doe\nsections:\n  - title: Experience\n    entries:\n      - type: entry\n        years: { start: 2019, end: 2021 }\n        job: Engineer\n        employer: Company\n        localization: City\n";
+    assert_eq!(entry(&from_str(yaml, Format::Yaml).unwrap()), range);
+
+    let yaml = yaml.replace("{ start: 2019, end: 2021 }", "2020");
+    assert_eq!(
+        entry(&from_str(&yaml, Format::Yaml).unwrap()),
+        Years::from("2020")
+    );
+
+    let toml = "[personal]\nfirstname = \"John\"\nlastname = \"Doe\"\n\n[[sections]]\ntitle = \"Experience\"\n\n[[sections.entries]]\ntype = \"entry\"\nyears = { start = 2019, end = 2021 }\njob = \"Engineer\"\nemployer = \"Company\"\nlocalization = \"City\"\n";
+    assert_eq!(entry(&from_str(toml, Format::Toml).unwrap()), range);
+}
+
+#[test]
+fn test_format() {
+    use std::path::Path;
+
+    assert_eq!(Format::from_path(Path::new("cv.yml")), Some(Format::Yaml));
+    assert_eq!(Format::from_path(Path::new("cv.TOML")), Some(Format::Toml));
+    assert_eq!(Format::from_path(Path::new("cv.json")), Some(Format::Json));
+    assert_eq!(Format::from_path(Path::new("cv.tex")), None);
+    assert!(matches!(from_path("cv.tex"), Err(Error::UnknownFormat(_))));
+}
+
+#[test]
+fn test_errors() {
+    let yaml = "personal:\n  firstname: John\n  lastname: Doe\ntheme:\n  style: modern\n";
+    let e = from_str(yaml, Format::Yaml).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("line 5, column 10: theme.style: unknown variant `modern`"));
+
+    let toml = "[personal]\nfirstname = \"John\"\nlastname = \"Doe\"\nnickname = \"JD\"\n";
+    let e = from_str(toml, Format::Toml).unwrap_err();
+    assert!(matches!(
+        e,
+        Error::Parse {
+            position: Some(Position { line: 4, column: 1 }),
+            ..
+        }
+    ));
+    assert!(e.to_string().contains("unknown field `nickname`"));
+
+    let json = "{\n  \"personal\": {\n    \"firstname\": \"John\"\n  }\n}";
+    let e = from_str(json, Format::Json).unwrap_err();
+    assert_eq!(e.to_string(), "line 4, column 3: missing field `lastname`");
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema() {
+    use moderncv::{Color, SocialType, Style};
+
+    let schema: serde_json::Value = serde_json::from_str(&json_schema()).unwrap();
+    let definitions = &schema["definitions"];
+
+    let names = |name: &str| -> Vec<String> {
+        definitions[name]["enum"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let styles = [
+        Style::Banking,
+        Style::Casual,
+        Style::Classic,
+        Style::Empty,
+        Style::Fancy,
+        Style::OldStyle,
+    ];
+    let colors = [
+        Color::Black,
+        Color::Blue,
+        Color::Burgundy,
+        Color::Green,
+        Color::Grey,
+        Color::Orange,
+        Color::Purple,
+        Color::Red,
+    ];
+    let phone_types = [PhoneType::Fixed, PhoneType::Mobile, PhoneType::Fax];
+    let social_types = [
+        SocialType::LinkedIn,
+        SocialType::XING,
+        SocialType::Twitter,
+        SocialType::Github,
+        SocialType::Gitlab,
+        SocialType::StackOverflow,
+        SocialType::Bitbucket,
+        SocialType::Skype,
+        SocialType::ORCID,
+        SocialType::ResearchGate,
+        SocialType::ResearcherID,
+        SocialType::Telegram,
+        SocialType::GoogleScholar,
+    ];
+
+    fn display<T: std::fmt::Display>(values: &[T]) -> Vec<String> {
+        values.iter().map(T::to_string).collect()
+    }
+
+    assert_eq!(names("Style"), display(&styles));
+    assert_eq!(names("Color"), display(&colors));
+    assert_eq!(names("PhoneType"), display(&phone_types));
+    assert_eq!(names("SocialType"), display(&social_types));
+
+    assert_eq!(schema["additionalProperties"], false);
+    assert_eq!(schema["required"], serde_json::json!(["personal"]));
+}
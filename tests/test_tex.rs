@@ -0,0 +1,43 @@
+use moderncv::tex::*;
+
+#[test]
+fn test_escape() {
+    assert_eq!(escape("plain text"), "plain text");
+    assert_eq!(
+        escape("R&D 50% $5 #1 a_b {c}"),
+        r"R\&D 50\% \$5 \#1 a\_b \{c\}"
+    );
+    assert_eq!(
+        escape(r"~ ^ \"),
+        r"\textasciitilde{} \textasciicircum{} \textbackslash{}"
+    );
+}
+
+#[test]
+fn test_unescape() {
+    assert_eq!(unescape("plain text"), "plain text");
+    assert_eq!(
+        unescape(r"R\&D 50\% \$5 \#1 a\_b \{c\}"),
+        "R&D 50% $5 #1 a_b {c}"
+    );
+    assert_eq!(
+        unescape(r"\textasciitilde{} \textasciicircum{} \textbackslash{}"),
+        r"~ ^ \"
+    );
+    assert_eq!(unescape(r"\emph{x}"), r"\emph{x}");
+
+    let s = r"~user/{a_b}#1 & 50% ^ \n";
+    assert_eq!(unescape(&escape(s)), s);
+}
+
+#[test]
+fn test_texstr() {
+    let text = TexStr::from("R&D");
+    assert_eq!(text.to_string(), r"R\&D");
+
+    let text = TexStr::from(String::from("my_account"));
+    assert_eq!(text.to_string(), r"my\_account");
+
+    let raw = TexStr::raw(r"\emph{Title}");
+    assert_eq!(raw.to_string(), r"\emph{Title}");
+}
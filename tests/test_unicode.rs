@@ -0,0 +1,119 @@
+use moderncv::metadata::Metadata;
+use moderncv::resume::{CVEntry, Photo, ResumeSection};
+use moderncv::unicode::*;
+use moderncv::{Engine, RenderOptions, Resume};
+
+#[test]
+fn test_transliterate() {
+    let (left, unrepresentable) = transliterate_str("Jürgen Łukasiewicz");
+    assert_eq!(left, r#"J\"{u}rgen \L{}ukasiewicz"#);
+    assert!(unrepresentable.is_empty());
+
+    let (left, _) = transliterate_str("Ångström – Škoda, Françoise, Dvořák, Gödel…");
+    assert_eq!(
+        left,
+        r#"\r{A}ngstr\"{o}m -- \v{S}koda, Fran\c{c}oise, Dvo\v{r}\'{a}k, G\"{o}del\ldots{}"#
+    );
+
+    let (left, unrepresentable) = transliterate_str("Acme 株式会社");
+    assert_eq!(left, "Acme 株式会社");
+    assert_eq!(unrepresentable, vec!['株', '式', '会', '社']);
+}
+
+#[test]
+fn test_pdflatex() {
+    let mut encoder = Encoder::new(Engine::Pdflatex, UnicodePolicy::Auto);
+
+    let left = encoder.encode(r"\cvitem{Name}{Jürgen \& Søren}");
+    assert_eq!(left, r#"\cvitem{Name}{J\"{u}rgen \& S\o{}ren}"#);
+    assert!(encoder.unrepresentable().is_empty());
+
+    let left = encoder.encode(r"\cvitem{City}{Москва}");
+    assert_eq!(left, r"\cvitem{City}{Москва}");
+
+    let unrepresentable = encoder.finish();
+    assert_eq!(unrepresentable.len(), 6);
+    assert_eq!(unrepresentable[0].character, 'М');
+    assert_eq!(unrepresentable[0].text, r"\cvitem{City}{Москва}");
+}
+
+#[test]
+fn test_cjk() {
+    let mut encoder = Encoder::new(Engine::Pdflatex, UnicodePolicy::Auto);
+
+    assert_eq!(
+        encoder.encode(r"\cvitem{Employer}{Acme 株式会社, 北京}"),
+        r"\cvitem{Employer}{Acme \begin{CJK}{UTF8}{gbsn}株式会社\end{CJK}, \begin{CJK}{UTF8}{gbsn}北京\end{CJK}}"
+    );
+    assert_eq!(
+        encoder.encode("とうきょう 서울"),
+        r"\begin{CJK}{UTF8}{min}とうきょう\end{CJK} \begin{CJK}{UTF8}{mj}서울\end{CJK}"
+    );
+    assert!(encoder.uses_cjk());
+    assert!(encoder.finish().is_empty());
+}
+
+#[test]
+fn test_unicode_engines() {
+    for engine in [Engine::Xelatex, Engine::Lualatex] {
+        let mut encoder = Encoder::new(engine, UnicodePolicy::Auto);
+
+        assert_eq!(encoder.encode("Jürgen 北京"), "Jürgen 北京");
+        assert!(!encoder.uses_cjk());
+        assert!(encoder.finish().is_empty());
+    }
+}
+
+#[test]
+fn test_policy() {
+    let mut encoder = Encoder::new(Engine::Pdflatex, UnicodePolicy::PassThrough);
+    assert_eq!(encoder.encode("Jürgen"), "Jürgen");
+
+    let mut encoder = Encoder::new(Engine::Xelatex, UnicodePolicy::Transliterate);
+    assert_eq!(encoder.encode("Jürgen"), r#"J\"{u}rgen"#);
+}
+
+#[test]
+fn test_render() {
+    let mut resume = Resume::new();
+    resume.personal.firstname = "Jürgen".into();
+    let mut experience = ResumeSection::new("Erfahrung in Москва");
+    experience.push(CVEntry::Item {
+        header: "Employer".into(),
+        text: "北京".into(),
+    });
+    resume.push(experience);
+
+    let rendered = resume.render(&RenderOptions::new());
+    let doc = latex::print(&rendered.document).unwrap();
+    assert!(doc.contains(r#"\firstname{J\"{u}rgen}"#));
+    assert!(doc.contains(r"\usepackage{CJKutf8}"));
+    assert!(doc.contains(r"\cvitem{Employer}{\begin{CJK}{UTF8}{gbsn}北京\end{CJK}}"));
+    assert_eq!(rendered.unrepresentable.len(), 6);
+    assert_eq!(rendered.unrepresentable[0].text, "Erfahrung in Москва");
+
+    let rendered = resume.render(RenderOptions::new().engine(Engine::Lualatex));
+    let doc = latex::print(&rendered.document).unwrap();
+    assert!(doc.contains(r"\firstname{Jürgen}"));
+    assert!(!doc.contains("CJKutf8"));
+    assert!(rendered.unrepresentable.is_empty());
+}
+
+#[test]
+fn test_preamble() {
+    let mut resume = Resume::new();
+    resume.personal.firstname = "北京".into();
+    resume.personal.photo = Some(Photo {
+        file: "fotó.jpg".to_string(),
+        ..Default::default()
+    });
+    resume.metadata = Some(Metadata::new().subject("Ingénieur").clone());
+
+    let rendered = resume.render(&RenderOptions::new());
+    let doc = latex::print(&rendered.document).unwrap();
+    assert!(doc.contains(r"\photo{fotó.jpg}"));
+    assert!(doc.contains(r"\firstname{\begin{CJK}{UTF8}{gbsn}北京\end{CJK}}"));
+    assert!(doc.contains("pdfauthor={北京"));
+    assert!(doc.contains(r"pdfsubject={Ing\'{e}nieur}"));
+    assert!(rendered.unrepresentable.is_empty());
+}